@@ -10,6 +10,9 @@ APPROACH:
 - Multiple detection algorithms (entropy, keyword, pattern-based)
 - Configurable filters to reduce false positives
 - Industry-standard secret detection patterns
+- Follows Rust include_str!/include_bytes! into files outside the scanned extensions
+- Decodes printable Rust byte-array literals so split-up secrets are still matched
+- Parses quoted URLs and flags passwords or tokens in their userinfo by host
 
 EXTENDS: BaseAnalyzer for common analyzer infrastructure
 - Inherits file scanning, CLI, configuration, and result formatting
@@ -22,14 +25,85 @@ REPLACES: detect_secrets.py with bespoke regex patterns
 - Better false positive filtering
 """
 
+import hashlib
 import json
+import re
 import subprocess
 import sys
+import tempfile
+from pathlib import Path
 from typing import Any, Optional
+from urllib.parse import urlsplit
 
 # Import base analyzer (package root must be on PYTHONPATH)
 from core.base.analyzer_base import AnalyzerConfig, BaseAnalyzer
 from core.base.analyzer_registry import register_analyzer
+from core.utils.redaction import mask_value
+
+# include_str!("path") / include_bytes!("path") with a plain string literal
+INCLUDE_MACRO_PATTERN = re.compile(r'\binclude_(?:str|bytes)!\s*\(\s*"([^"\\]+)"\s*\)')
+
+# Array literals of byte values: [0x67, 0x68, ...], [103u8, ...] or [b'g', ...]
+_BYTE_VALUE = r"(?:0x[0-9a-fA-F]{1,2}|\d{1,3}|b'[^'\\]')(?:_?u8)?"
+BYTE_ARRAY_PATTERN = re.compile(
+    rf"\[\s*({_BYTE_VALUE}(?:\s*,\s*{_BYTE_VALUE})*)\s*,?\s*\]"
+)
+
+# Shorter decoded arrays are almost always lookup tables or magic numbers
+MIN_BYTE_ARRAY_SECRET_LENGTH = 16
+
+# Quoted absolute URLs; userinfo is checked after parsing
+URL_LITERAL_PATTERN = re.compile(r"""["']([a-zA-Z][a-zA-Z0-9+.-]*://[^"'\s]+)["']""")
+
+# userinfo/host split for URLs urllib rejects, e.g. a malformed IPv6 host
+_URL_USERINFO_PATTERN = re.compile(
+    r"^[^:/]+://(?P<user>[^:@/]*)(?::(?P<password>[^@/]*))?@"
+    r"(?P<host>\[[^\]/]*\]?|[^/:?#]*)"
+)
+
+# Template variables and documentation stand-ins rather than credentials
+_PLACEHOLDER_CREDENTIAL = re.compile(
+    r"[{}$<>%]|^(?:\*+|x+|pass(?:word)?|token)$", re.IGNORECASE
+)
+
+# A username with no password only counts as a credential when token-length
+MIN_URL_TOKEN_LENGTH = 20
+
+
+def _decode_byte_values(values: str) -> Optional[str]:
+    """Decode a byte-array literal body; None unless every byte is printable ASCII."""
+    chars = []
+    for value in values.split(","):
+        token = value.strip().removesuffix("u8").rstrip("_")
+        if token.startswith("b'"):
+            code = ord(token[2])
+        elif token.lower().startswith("0x"):
+            code = int(token, 16)
+        else:
+            code = int(token)
+        if not 0x20 <= code <= 0x7E:
+            return None
+        chars.append(chr(code))
+    return "".join(chars)
+
+
+def _url_credential(url: str) -> Optional[tuple[str, str]]:
+    """Return (credential, host) if the URL's userinfo holds a password or token."""
+    try:
+        parts = urlsplit(url)
+        user, password, host = parts.username, parts.password, parts.hostname
+    except ValueError:
+        match = _URL_USERINFO_PATTERN.match(url)
+        if match is None:
+            return None
+        user, password, host = match.group("user", "password", "host")
+
+    credential = password
+    if not credential and user and len(user) >= MIN_URL_TOKEN_LENGTH:
+        credential = user
+    if not credential or _PLACEHOLDER_CREDENTIAL.search(credential):
+        return None
+    return credential, host or ""
 
 
 @register_analyzer("security:detect_secrets")
@@ -110,6 +184,9 @@ class DetectSecretsAnalyzer(BaseAnalyzer):
         # Initialize base analyzer
         super().__init__("security", security_config)
 
+        # Included files already scanned in this run, so shared includes report once
+        self._scanned_includes: set[Path] = set()
+
         # Check for detect-secrets availability
         self.detect_secrets_available = True  # Will be set to False if not available
         self._check_detect_secrets_availability()
@@ -169,6 +246,11 @@ class DetectSecretsAnalyzer(BaseAnalyzer):
             ],
         }
 
+    def start_analysis(self) -> None:
+        """Start a run (analyze, analyze_source, scan_staged) with no includes seen."""
+        super().start_analysis()
+        self._scanned_includes = set()
+
     def _is_testing_environment(self) -> bool:
         """Detect if we're running in a testing environment."""
         import os
@@ -351,7 +433,10 @@ class DetectSecretsAnalyzer(BaseAnalyzer):
                 "description": f"Hardcoded {secret_type.lower()} detected",
                 "recommendation": self._get_secret_recommendation(secret_type),
                 "pattern_matched": f"detect-secrets: {secret_type}",
-                "confidence": "high",
+                # Entropy-only matches have no structural signal behind them
+                "confidence": "medium" if "High Entropy" in secret_type else "high",
+                # detect-secrets hashes the value (SHA-1); the raw secret is never kept
+                "hashed_secret": secret.get("hashed_secret"),
             }
 
         except Exception as e:
@@ -370,6 +455,7 @@ class DetectSecretsAnalyzer(BaseAnalyzer):
             "High Entropy String": "Review high entropy strings. If secrets, move to environment variables or secure vaults.",
             "Basic Auth": "Remove hardcoded authentication. Use secure credential storage and environment variables.",
             "API Key": "Remove API keys from code. Use environment variables or secure configuration management.",
+            "URL Credentials": "Remove credentials from the URL. Pass them separately (e.g. basic_auth / bearer_auth on the request) from a secret store or environment variable.",
         }
 
         return recommendations.get(
@@ -398,6 +484,19 @@ class DetectSecretsAnalyzer(BaseAnalyzer):
             return []
 
         findings = self._run_detect_secrets_scan(target_path)
+        # The URL finding names the host, so it replaces detect-secrets' own
+        url_findings = self._scan_url_credentials(Path(target_path))
+        url_lines = {(f["file_path"], f["line_number"]) for f in url_findings}
+        findings = [
+            finding
+            for finding in findings
+            if finding["perf_type"] != "basic_auth_credentials"
+            or (finding["file_path"], finding["line_number"]) not in url_lines
+        ]
+        findings.extend(url_findings)
+        if target_path.endswith(".rs"):
+            findings.extend(self._scan_included_files(Path(target_path)))
+            findings.extend(self._scan_byte_array_literals(Path(target_path)))
 
         # Convert to our standardized format for BaseAnalyzer
         standardized_findings = []
@@ -414,12 +513,144 @@ class DetectSecretsAnalyzer(BaseAnalyzer):
                     "secret_type": finding["perf_type"],
                     "category": finding["category"],
                     "confidence": finding["confidence"],
+                    "hashed_secret": finding["hashed_secret"],
+                    "line_content": finding["line_content"],
                 },
             }
+            if "included_from" in finding:
+                standardized["metadata"]["included_from"] = finding["included_from"]
+            if "decoded_from" in finding:
+                standardized["metadata"]["decoded_from"] = finding["decoded_from"]
+            if "url_host" in finding:
+                standardized["metadata"]["url_host"] = finding["url_host"]
+                standardized["metadata"]["masked_credential"] = finding[
+                    "masked_credential"
+                ]
             standardized_findings.append(standardized)
 
         return standardized_findings
 
+    def _scan_included_files(self, source_path: Path) -> list[dict[str, Any]]:
+        """
+        Scan files embedded via include_str!/include_bytes! in a Rust source file.
+
+        Paths resolve relative to the including file, as rustc does. Files whose
+        extension is already scanned are skipped to avoid duplicate findings.
+        Findings are attributed to the included file with the include site noted.
+        """
+        try:
+            source = source_path.read_text(encoding="utf-8", errors="ignore")
+        except OSError:
+            return []
+
+        findings = []
+        for line_number, line in enumerate(source.splitlines(), 1):
+            for match in INCLUDE_MACRO_PATTERN.finditer(line):
+                included = (source_path.parent / match.group(1)).resolve()
+                if (
+                    not included.is_file()
+                    or included.suffix.lower() in self.config.code_extensions
+                    or included in self._scanned_includes
+                ):
+                    continue
+                self._scanned_includes.add(included)
+
+                for finding in self._run_detect_secrets_scan(str(included)):
+                    finding["included_from"] = f"{source_path}:{line_number}"
+                    finding["description"] += f" (embedded via {match.group(0)})"
+                    findings.append(finding)
+        return findings
+
+    def _scan_byte_array_literals(self, source_path: Path) -> list[dict[str, Any]]:
+        """
+        Scan printable ASCII byte-array literals in a Rust source file as strings.
+
+        Arrays of at least MIN_BYTE_ARRAY_SECRET_LENGTH printable bytes are
+        decoded and written one per line to a scratch file for detect-secrets,
+        so each finding keeps the severity of the pattern that matched and is
+        mapped back to the line where its array starts.
+        """
+        try:
+            source = source_path.read_text(encoding="utf-8", errors="ignore")
+        except OSError:
+            return []
+
+        decoded_lines: list[tuple[int, str]] = []
+        for match in BYTE_ARRAY_PATTERN.finditer(source):
+            decoded = _decode_byte_values(match.group(1))
+            if decoded is not None and len(decoded) >= MIN_BYTE_ARRAY_SECRET_LENGTH:
+                line_number = source.count("\n", 0, match.start()) + 1
+                decoded_lines.append((line_number, decoded))
+        if not decoded_lines:
+            return []
+
+        findings = []
+        with tempfile.TemporaryDirectory(prefix="scan-bytes-") as scratch:
+            decoded_file = Path(scratch) / f"{source_path.name}.decoded"
+            decoded_file.write_text(
+                "".join(f'"{value}"\n' for _, value in decoded_lines),
+                encoding="utf-8",
+            )
+            for finding in self._run_detect_secrets_scan(str(decoded_file)):
+                index = finding["line_number"] - 1
+                if not 0 <= index < len(decoded_lines):
+                    continue
+                finding["file_path"] = str(source_path)
+                finding["line_number"] = decoded_lines[index][0]
+                finding["decoded_from"] = "byte-array"
+                finding["description"] += " (decoded from a byte-array literal)"
+                findings.append(finding)
+        return findings
+
+    def _scan_url_credentials(self, source_path: Path) -> list[dict[str, Any]]:
+        """
+        Flag quoted URLs whose userinfo carries a password or token.
+
+        Each literal is parsed with urllib, falling back to a regex for URLs it
+        rejects. Findings name the host and only ever hold the credential
+        masked, in the description and the line content (plus its SHA-1, as
+        detect-secrets reports it).
+        """
+        try:
+            source = source_path.read_text(encoding="utf-8", errors="ignore")
+        except OSError:
+            return []
+
+        findings = []
+        for line_number, line in enumerate(source.splitlines(), 1):
+            for match in URL_LITERAL_PATTERN.finditer(line):
+                found = _url_credential(match.group(1))
+                if found is None:
+                    continue
+                credential, host = found
+                masked = mask_value(credential)
+                # Context for the report and fingerprint, never the raw value
+                redacted_line = line.strip().replace(credential, masked)[:150]
+                findings.append(
+                    {
+                        "perf_type": "url_embedded_credentials",
+                        "category": "secrets",
+                        "file_path": str(source_path),
+                        "line_number": line_number,
+                        "line_content": redacted_line,
+                        "severity": "high",
+                        "description": (
+                            f"Hardcoded credential ({masked}) in a URL for {host}"
+                        ),
+                        "recommendation": self._get_secret_recommendation(
+                            "URL Credentials"
+                        ),
+                        "pattern_matched": "url-userinfo",
+                        "confidence": "high",
+                        "hashed_secret": hashlib.sha1(
+                            credential.encode("utf-8")
+                        ).hexdigest(),
+                        "url_host": host,
+                        "masked_credential": masked,
+                    }
+                )
+        return findings
+
     def get_analyzer_metadata(self) -> dict[str, Any]:
         """Return metadata about this analyzer."""
         return {
@@ -435,6 +666,9 @@ class DetectSecretsAnalyzer(BaseAnalyzer):
                 "Cloud credentials detection",
                 "JWT token discovery",
                 "Basic authentication detection",
+                "Rust include_str!/include_bytes! resolution",
+                "Rust byte-array literal decoding",
+                "URL userinfo credential detection",
                 "Multi-plugin analysis",
                 "False positive filtering",
             ],
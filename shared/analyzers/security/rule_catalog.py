@@ -0,0 +1,120 @@
+#!/usr/bin/env python3
+"""
+Rule Catalog - machine-readable listing of the bundled Semgrep rules.
+
+PURPOSE: Feed documentation generators and other tooling without running a
+scan. Lists every rule in `rules/rust-security.yml` plus any rule-DSL
+manifests in the active config, and marks rules that config would not
+report (suppressed, or opt-in without an enabled tag). `explain_rule`
+renders one rule's description, CWE, severity and the vulnerable/fixed
+examples kept in its `example`/`fix` metadata, for offline `--explain`.
+"""
+
+import textwrap
+from pathlib import Path
+from typing import Any, Optional
+
+import yaml
+from analyzers.security.rules_dsl import load_rule_manifest
+from core.base.analyzer_base import AnalyzerConfig
+from core.base.rule_aliases import canonical_rule_id
+
+RUST_RULES_PATH = Path(__file__).parent / "rules" / "rust-security.yml"
+
+# Same mapping SemgrepAnalyzer applies to findings, including the
+# metadata.severity override
+_SEVERITIES = {"ERROR": "critical", "WARNING": "high", "INFO": "medium"}
+_SEVERITY_LEVELS = {"critical", "high", "medium", "low", "info"}
+
+
+def _catalog_entry(
+    rule: dict[str, Any], source: str, config: AnalyzerConfig
+) -> dict[str, Any]:
+    metadata = rule.get("metadata", {})
+    tags = list(metadata.get("tags", []))
+    references = metadata.get("references") or []
+    severity = metadata.get("severity")
+    if severity not in _SEVERITY_LEVELS:
+        severity = _SEVERITIES.get(rule.get("severity", ""), "medium")
+
+    disabled_reason: Optional[str] = None
+    if rule["id"] in {canonical_rule_id(r) for r in config.suppressed_rules}:
+        disabled_reason = "suppressed"
+    elif "opt-in" in tags and not set(tags) & config.enabled_rule_tags:
+        disabled_reason = "opt-in"
+
+    return {
+        "id": rule["id"],
+        "name": rule["id"].removeprefix("rust-").replace("-", " ").capitalize(),
+        "description": rule.get("message", ""),
+        "severity": severity,
+        "confidence": str(metadata.get("confidence", "high")).lower(),
+        "languages": rule.get("languages", []),
+        "tags": tags,
+        "cwe": metadata.get("cwe"),
+        "owasp": metadata.get("owasp"),
+        "help_uri": references[0] if references else None,
+        "references": references,
+        "example": metadata.get("example"),
+        "fix": metadata.get("fix"),
+        "source": source,
+        "enabled": disabled_reason is None,
+        "disabled_reason": disabled_reason,
+    }
+
+
+def list_rules(config: Optional[AnalyzerConfig] = None) -> list[dict[str, Any]]:
+    """Return catalog entries for the bundled and configured rules, sorted by id."""
+    config = config or AnalyzerConfig()
+    sources = [
+        (RUST_RULES_PATH.name, yaml.safe_load(RUST_RULES_PATH.read_text())["rules"])
+    ]
+    sources.extend(
+        (str(manifest), load_rule_manifest(manifest))
+        for manifest in config.rule_manifests
+    )
+    entries = [
+        _catalog_entry(rule, source, config)
+        for source, rules in sources
+        for rule in rules
+    ]
+    return sorted(entries, key=lambda entry: entry["id"])
+
+
+def find_rule(
+    rule_id: str, config: Optional[AnalyzerConfig] = None
+) -> Optional[dict[str, Any]]:
+    """Look up a catalog entry by id, legacy alias, or id without `rust-`."""
+    entries = {entry["id"]: entry for entry in list_rules(config)}
+    for candidate in (rule_id, f"rust-{rule_id}"):
+        entry = entries.get(canonical_rule_id(candidate))
+        if entry:
+            return entry
+    return None
+
+
+def explain_rule(entry: dict[str, Any]) -> str:
+    """Render a catalog entry as plain text, like `rustc --explain`."""
+
+    def indented(code: str) -> str:
+        return textwrap.indent(code.rstrip("\n"), "    ")
+
+    lines = [
+        f"{entry['id']} ({entry['severity']} severity, "
+        f"{entry['confidence']} confidence)",
+    ]
+    if entry["cwe"]:
+        lines.append(entry["cwe"])
+    if entry["owasp"]:
+        lines.append(f"OWASP {entry['owasp']}")
+    if not entry["enabled"]:
+        lines.append(f"Disabled in this configuration ({entry['disabled_reason']})")
+    lines.extend(["", textwrap.fill(entry["description"], width=79)])
+    if entry["example"]:
+        lines.extend(["", "Vulnerable example:", "", indented(entry["example"])])
+    if entry["fix"]:
+        lines.extend(["", "Fix:", "", indented(entry["fix"])])
+    if entry["references"]:
+        lines.extend(["", "References:"])
+        lines.extend(f"  {reference}" for reference in entry["references"])
+    return "\n".join(lines)
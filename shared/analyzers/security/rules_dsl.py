@@ -0,0 +1,146 @@
+#!/usr/bin/env python3
+"""
+Rule DSL - declarative taint rules compiled to Semgrep.
+
+PURPOSE: Let security teams add simple "tainted value reaches call X" rules
+without writing Semgrep patterns. A manifest (YAML or TOML) lists rules;
+each is validated and compiled into a Semgrep taint rule, then passed to
+Semgrep alongside the bundled rulesets.
+
+SCHEMA (one entry under top-level `rules`):
+    id:          unique rule id (required)
+    message:     finding message (required)
+    severity:    ERROR | WARNING | INFO (default WARNING)
+    language:    Semgrep language name (default rust)
+    confidence:  HIGH | MEDIUM | LOW (default MEDIUM)
+    cwe:         optional CWE string
+    sources:     list of `handler-params` (parameters of async warp handlers)
+                 or `{function: PATH}` (the return value of a call)
+                 (default [handler-params])
+    sinks:       non-empty list of `{function: PATH, tainted_arg: N}` or
+                 `{method: NAME, tainted_arg: N}`; N is the 0-based argument
+                 whose taint triggers the finding (default 0)
+    sanitizers:  optional list of `{function: PATH}` / `{method: NAME}`
+
+LIMITS: Only call sinks with positional arguments are expressible; anything
+needing pattern-inside context or metavariable filters still belongs in a
+Semgrep YAML ruleset.
+"""
+
+import tomllib
+from pathlib import Path
+from typing import Any, Union
+
+import yaml
+
+HANDLER_PARAMS = "handler-params"
+SEVERITIES = ("ERROR", "WARNING", "INFO")
+CONFIDENCES = ("HIGH", "MEDIUM", "LOW")
+
+_HANDLER_SOURCE = {
+    "patterns": [
+        {
+            "pattern-inside": (
+                "async fn $HANDLER(..., $PARAM: $TYPE, ...) "
+                "-> Result<$OK, warp::Rejection> { ... }\n"
+            )
+        },
+        {"pattern": "$PARAM"},
+    ]
+}
+
+
+def _callee(spec: dict[str, Any], rule_id: str) -> str:
+    if "function" in spec:
+        return str(spec["function"])
+    if "method" in spec:
+        return f"$RECEIVER.{spec['method']}"
+    raise ValueError(f"{rule_id}: call spec needs 'function' or 'method': {spec}")
+
+
+def _compile_sink(spec: dict[str, Any], rule_id: str) -> dict[str, Any]:
+    index = spec.get("tainted_arg", 0)
+    if not isinstance(index, int) or index < 0:
+        raise ValueError(f"{rule_id}: tainted_arg must be a non-negative integer")
+    args = ["$_"] * index + ["$TAINTED", "..."]
+    return {
+        "patterns": [
+            {"pattern": f"{_callee(spec, rule_id)}({', '.join(args)})"},
+            {"focus-metavariable": "$TAINTED"},
+        ]
+    }
+
+
+def _compile_source(spec: Union[str, dict[str, Any]], rule_id: str) -> dict:
+    if spec == HANDLER_PARAMS:
+        return _HANDLER_SOURCE
+    if isinstance(spec, dict):
+        return {"pattern": f"{_callee(spec, rule_id)}(...)"}
+    raise ValueError(f"{rule_id}: unknown source {spec!r}")
+
+
+def compile_rule(spec: dict[str, Any]) -> dict[str, Any]:
+    """Validate one DSL rule and return the equivalent Semgrep rule."""
+    rule_id = spec.get("id")
+    if not rule_id or not spec.get("message"):
+        raise ValueError(f"DSL rule needs 'id' and 'message': {spec}")
+    severity = str(spec.get("severity", "WARNING")).upper()
+    confidence = str(spec.get("confidence", "MEDIUM")).upper()
+    if severity not in SEVERITIES:
+        raise ValueError(f"{rule_id}: severity must be one of {SEVERITIES}")
+    if confidence not in CONFIDENCES:
+        raise ValueError(f"{rule_id}: confidence must be one of {CONFIDENCES}")
+    if not spec.get("sinks"):
+        raise ValueError(f"{rule_id}: at least one sink is required")
+
+    metadata = {"category": "security", "confidence": confidence}
+    if spec.get("cwe"):
+        metadata["cwe"] = spec["cwe"]
+    rule = {
+        "id": rule_id,
+        "mode": "taint",
+        "pattern-sources": [
+            _compile_source(source, rule_id)
+            for source in spec.get("sources", [HANDLER_PARAMS])
+        ],
+        "pattern-sinks": [_compile_sink(sink, rule_id) for sink in spec["sinks"]],
+        "message": spec["message"],
+        "languages": [spec.get("language", "rust")],
+        "severity": severity,
+        "metadata": metadata,
+    }
+    if spec.get("sanitizers"):
+        rule["pattern-sanitizers"] = [
+            {"pattern": f"{_callee(sanitizer, rule_id)}(...)"}
+            for sanitizer in spec["sanitizers"]
+        ]
+    return rule
+
+
+def load_rule_manifest(path: Union[str, Path]) -> list[dict[str, Any]]:
+    """Load a `.yml`/`.yaml`/`.toml` manifest and compile its rules."""
+    manifest = Path(path)
+    try:
+        if manifest.suffix == ".toml":
+            data = tomllib.loads(manifest.read_text(encoding="utf-8"))
+        else:
+            data = yaml.safe_load(manifest.read_text(encoding="utf-8"))
+    except (OSError, tomllib.TOMLDecodeError, yaml.YAMLError) as e:
+        raise ValueError(f"Invalid rule manifest {manifest}: {e}") from e
+    if not isinstance(data, dict) or not isinstance(data.get("rules"), list):
+        raise ValueError(f"Rule manifest {manifest} needs a top-level 'rules' list")
+    return [compile_rule(spec) for spec in data["rules"]]
+
+
+def write_semgrep_config(
+    manifests: list[Union[str, Path]], destination: Union[str, Path]
+) -> Path:
+    """Compile manifests into a single Semgrep config file and return its path."""
+    rules = [rule for manifest in manifests for rule in load_rule_manifest(manifest)]
+    ids = [rule["id"] for rule in rules]
+    duplicates = sorted({rule_id for rule_id in ids if ids.count(rule_id) > 1})
+    if duplicates:
+        raise ValueError(f"Duplicate DSL rule ids: {', '.join(duplicates)}")
+    config = Path(destination)
+    config.write_text(yaml.safe_dump({"rules": rules}, sort_keys=False))
+    return config
@@ -23,14 +23,31 @@ REPLACES: Multiple bespoke analyzers with regex patterns
 """
 
 import json
+import os
 import subprocess
 import sys
+import tempfile
+from collections.abc import Iterator
+from contextlib import contextmanager
 from pathlib import Path
 from typing import Any, Optional
 
 # Import base analyzer (package root must be on PYTHONPATH)
+from analyzers.security.rules_dsl import write_semgrep_config
 from core.base.analyzer_base import AnalyzerConfig, BaseAnalyzer
 from core.base.analyzer_registry import register_analyzer
+from core.base.ignore_rules import ScanIgnore
+
+RUST_RULES_PATH = Path(__file__).parent / "rules" / "rust-security.yml"
+
+
+def _span(finding: dict[str, Any]) -> dict[str, int]:
+    """Column and end position Semgrep reported for a finding, when present."""
+    return {
+        key: finding[key]
+        for key in ("column", "end_line", "end_column")
+        if finding.get(key) is not None
+    }
 
 
 @register_analyzer("security:semgrep")
@@ -116,6 +133,7 @@ class SemgrepAnalyzer(BaseAnalyzer):
 
         # Check for Semgrep availability
         self.semgrep_available = True  # Will be set to False if not available
+        self.semgrep_version = ""
         self._check_semgrep_availability()
 
         # Semgrep rule configurations for different security categories
@@ -137,6 +155,35 @@ class SemgrepAnalyzer(BaseAnalyzer):
             "INFO": "medium",
         }
 
+    @contextmanager
+    def _rule_configs(self, include_rust: bool = True) -> Iterator[list[str]]:
+        """
+        Yield the bundled and rule-DSL configs to pass alongside registry rules.
+
+        Manifests are compiled into a temporary config that is removed once the
+        Semgrep run inside the block has finished.
+        """
+        configs = []
+        if include_rust and RUST_RULES_PATH.exists():
+            configs.append(str(RUST_RULES_PATH))
+        dsl_config = None
+        if self.config.rule_manifests:
+            with tempfile.NamedTemporaryFile(
+                prefix="semgrep-dsl-", suffix=".yml", delete=False
+            ) as handle:
+                dsl_config = handle.name
+            try:
+                write_semgrep_config(self.config.rule_manifests, dsl_config)
+            except Exception:
+                os.unlink(dsl_config)
+                raise
+            configs.append(dsl_config)
+        try:
+            yield configs
+        finally:
+            if dsl_config:
+                os.unlink(dsl_config)
+
     def _is_testing_environment(self) -> bool:
         """Detect if we're running in a testing environment."""
         import os
@@ -184,6 +231,7 @@ class SemgrepAnalyzer(BaseAnalyzer):
             version = result.stdout.strip()
             print(f"Found Semgrep {version}", file=sys.stderr)
             self.semgrep_available = True
+            self.semgrep_version = version
 
         except (subprocess.TimeoutExpired, FileNotFoundError):
             print("WARNING: Semgrep is required but not available.", file=sys.stderr)
@@ -204,6 +252,35 @@ class SemgrepAnalyzer(BaseAnalyzer):
                 )
                 self.semgrep_available = False
 
+    def toolchain_version(self) -> str:
+        return self.semgrep_version
+
+    def cache_inputs(self, target_path: str) -> list[Path]:
+        """
+        Every file under target_path Semgrep may read.
+
+        analyze() hands Semgrep the whole directory and it picks files by its
+        own rules, so key on all of them except `.git` and ignored paths.
+        """
+        target = Path(target_path)
+        scan_ignore = ScanIgnore(target)
+        candidates = [target] if target.is_file() else target.rglob("*")
+        return [
+            file_path
+            for file_path in candidates
+            if file_path.is_file()
+            and ".git" not in file_path.parts
+            and not scan_ignore.is_ignored(file_path)
+        ]
+
+    def applicable_rules(self, file_path: Path) -> list[str]:
+        """Semgrep configs a batch scan passes for this file."""
+        rules = ["auto"]
+        if file_path.suffix == ".rs":
+            rules.append(str(RUST_RULES_PATH))
+        rules.extend(self.config.rule_manifests)
+        return rules
+
     def _run_semgrep_batch_analysis(
         self, file_paths: list[str]
     ) -> list[dict[str, Any]]:
@@ -222,48 +299,37 @@ class SemgrepAnalyzer(BaseAnalyzer):
                 "scan",
                 "--config=auto",  # Auto includes security rules
                 "--json",
+                "--dataflow-traces",  # Source-to-sink steps for taint rules
                 "--timeout",
                 "10",  # Faster timeout per file
                 "--timeout-threshold",
                 "3",  # Skip slow files quickly
                 "--max-target-bytes",
-                "500000",  # Skip files > 500KB
+                self._max_target_bytes(),
                 "--jobs",
-                "4",  # Parallel processing
+                str(self._semgrep_jobs()),  # Parallel processing
                 "--optimizations",
                 "all",  # Enable all optimizations
                 "--oss-only",  # Use only OSS rules for speed
             ]
 
             # Add custom Rust security rules if analyzing Rust files
-            import os
-
             rust_files = [fp for fp in file_paths if fp.endswith(".rs")]
-            if rust_files:
-                rust_rules_path = os.path.join(
-                    os.path.dirname(__file__), "rules", "rust-security.yml"
-                )
-                if os.path.exists(rust_rules_path):
-                    cmd.extend(["--config", rust_rules_path])
-                    # Debug output for custom rules
-                    import sys
-
-                    print(
-                        f"Added custom Rust rules for {len(rust_files)} Rust files: {rust_rules_path}",
-                        file=sys.stderr,
-                    )
+            with self._rule_configs(include_rust=bool(rust_files)) as configs:
+                for config in configs:
+                    cmd.extend(["--config", config])
 
-            # Add all file paths to analyze in batch
-            cmd.extend(file_paths)
+                # Add all file paths to analyze in batch
+                cmd.extend(file_paths)
 
-            self.logger.info(f"Running semgrep on {len(file_paths)} files in batch")
+                self.logger.info(f"Running semgrep on {len(file_paths)} files in batch")
 
-            result = subprocess.run(
-                cmd,
-                capture_output=True,
-                text=True,
-                timeout=60,  # 1 minute total timeout for batch
-            )
+                result = subprocess.run(
+                    cmd,
+                    capture_output=True,
+                    text=True,
+                    timeout=60,  # 1 minute total timeout for batch
+                )
 
             if result.stdout:
                 semgrep_output = json.loads(result.stdout)
@@ -285,7 +351,7 @@ class SemgrepAnalyzer(BaseAnalyzer):
             # Fallback to individual file analysis if batch fails
             return self._fallback_individual_analysis(file_paths)
 
-        return findings
+        return self._sort_findings(findings)
 
     def _fallback_individual_analysis(
         self, file_paths: list[str]
@@ -312,13 +378,14 @@ class SemgrepAnalyzer(BaseAnalyzer):
                     "--config",
                     ruleset,
                     "--json",
+                    "--dataflow-traces",  # Source-to-sink steps for taint rules
                     # "--no-git-ignore",  # Let Semgrep use built-in exclusions and .gitignore
                     "--timeout",
                     "10",  # Faster timeout per file
                     "--timeout-threshold",
                     "3",  # Skip slow files
                     "--max-target-bytes",
-                    "500000",  # Skip files > 500KB
+                    self._max_target_bytes(),
                     target_path,
                 ]
 
@@ -362,9 +429,19 @@ class SemgrepAnalyzer(BaseAnalyzer):
             path = finding.get("path", "")
             start_line = finding.get("start", {}).get("line", 0)
             severity = finding.get("extra", {}).get("severity", "WARNING")
+            metadata = finding.get("extra", {}).get("metadata", {})
+            confidence = metadata.get("confidence", "HIGH")
+
+            # Opt-in rules only report when one of their tags is enabled
+            tags = set(metadata.get("tags", []))
+            if "opt-in" in tags and not tags & self.config.enabled_rule_tags:
+                return None
 
-            # Map Semgrep severity to our levels
-            our_severity = self.severity_mapping.get(severity, "medium")
+            # Map Semgrep severity to our levels; Semgrep has no "low", so
+            # rules may name one of ours in metadata.severity instead
+            our_severity = metadata.get("severity")
+            if our_severity not in {"critical", "high", "medium", "low", "info"}:
+                our_severity = self.severity_mapping.get(severity, "medium")
 
             # Create standardized finding
             return {
@@ -372,18 +449,66 @@ class SemgrepAnalyzer(BaseAnalyzer):
                 "category": self._get_category_from_ruleset(ruleset),
                 "file_path": path,
                 "line_number": start_line,
+                "column": finding.get("start", {}).get("col"),
+                "end_line": finding.get("end", {}).get("line"),
+                "end_column": finding.get("end", {}).get("col"),
                 "line_content": finding.get("extra", {}).get("lines", "")[:150],
                 "severity": our_severity,
                 "description": message,
                 "recommendation": self._get_recommendation(check_id),
                 "pattern_matched": f"Semgrep: {check_id}",
-                "confidence": "high",  # Semgrep provides high confidence findings
+                "confidence": confidence.lower(),  # Rule metadata, default high
+                "flow": self._extract_flow(finding),
             }
 
         except Exception as e:
             self.logger.warning(f"Failed to process Semgrep finding: {e}")
             return None
 
+    @staticmethod
+    def _extract_flow(finding: dict[str, Any]) -> Optional[list[dict[str, Any]]]:
+        """
+        Convert Semgrep's dataflow trace into ordered source/propagation/sink steps.
+
+        Only taint-mode findings carry a trace; others return None.
+        """
+        trace = finding.get("extra", {}).get("dataflow_trace")
+        if not trace:
+            return None
+
+        def step(kind: str, location: dict[str, Any], content: str) -> dict[str, Any]:
+            return {
+                "kind": kind,
+                "file_path": location.get("path", finding.get("path", "")),
+                "line_number": location.get("start", {}).get("line", 0),
+                "column": location.get("start", {}).get("col", 0),
+                "content": content,
+            }
+
+        def call_site(node: Any) -> Optional[tuple[dict[str, Any], str]]:
+            # ["CliLoc", [location, content]] or ["CliCall", [[location, content], ...]]
+            if not isinstance(node, list) or len(node) != 2:
+                return None
+            kind, payload = node
+            if kind == "CliCall":
+                payload = payload[0] if payload else None
+            if isinstance(payload, list) and len(payload) == 2:
+                return payload[0], payload[1]
+            return None
+
+        steps = []
+        source = call_site(trace.get("taint_source"))
+        if source:
+            steps.append(step("source", *source))
+        for var in trace.get("intermediate_vars", []):
+            steps.append(
+                step("propagation", var.get("location", {}), var.get("content", ""))
+            )
+        sink = call_site(trace.get("taint_sink"))
+        if sink:
+            steps.append(step("sink", *sink))
+        return steps or None
+
     def _get_category_from_ruleset(self, ruleset: str) -> str:
         """Map Semgrep ruleset to our category system."""
         if "injection" in ruleset:
@@ -417,7 +542,9 @@ class SemgrepAnalyzer(BaseAnalyzer):
             "Review this security finding and apply appropriate security controls",
         )
 
-    def _run_semgrep_on_directory(self, directory_path: str) -> list[dict[str, Any]]:
+    def _run_semgrep_on_directory(
+        self, directory_path: str, scan_ignore: Optional[ScanIgnore] = None
+    ) -> list[dict[str, Any]]:
         """
         Run Semgrep on entire directory, letting it handle exclusions.
 
@@ -433,28 +560,34 @@ class SemgrepAnalyzer(BaseAnalyzer):
                 "scan",
                 "--config=auto",  # Auto includes comprehensive security rules
                 "--json",
+                "--dataflow-traces",  # Source-to-sink steps for taint rules
                 "--timeout",
                 "10",  # Per-file timeout
                 "--timeout-threshold",
                 "3",  # Skip slow files
                 "--max-target-bytes",
-                "500000",  # Skip files > 500KB
+                self._max_target_bytes(),
                 "--jobs",
-                "4",  # Parallel processing
+                str(self._semgrep_jobs()),  # Parallel processing
                 "--optimizations",
                 "all",  # Enable all optimizations
                 "--oss-only",  # Use only OSS rules for speed
-                directory_path,  # Pass directory, not individual files
             ]
 
             self.logger.info(f"Running Semgrep on directory: {directory_path}")
 
-            result = subprocess.run(
-                cmd,
-                capture_output=True,
-                text=True,
-                timeout=300,  # 5 minute total timeout
-            )
+            # Bundled Rust rules only match .rs files, so they are always safe to add
+            with self._rule_configs() as configs:
+                for config in configs:
+                    cmd.extend(["--config", config])
+                cmd.append(directory_path)  # Pass directory, not individual files
+
+                result = subprocess.run(
+                    cmd,
+                    capture_output=True,
+                    text=True,
+                    timeout=300,  # 5 minute total timeout
+                )
 
             if result.stdout:
                 semgrep_output = json.loads(result.stdout)
@@ -469,8 +602,11 @@ class SemgrepAnalyzer(BaseAnalyzer):
                     f"Semgrep scanned {len(scanned_files)} files (down from BaseAnalyzer's file discovery)"
                 )
 
-                # Process Semgrep findings
+                # Process Semgrep findings, honoring .scannerignore exclusions
+                scan_ignore = scan_ignore or ScanIgnore(directory_path)
                 for finding in results:
+                    if scan_ignore.is_ignored(finding.get("path", "")):
+                        continue
                     processed_finding = self._process_semgrep_finding(finding, "auto")
                     if processed_finding:
                         findings.append(processed_finding)
@@ -489,7 +625,44 @@ class SemgrepAnalyzer(BaseAnalyzer):
             # Don't fallback to file-by-file - let it fail cleanly
             raise
 
-        return findings
+        return self._sort_findings(findings)
+
+    def _max_target_bytes(self) -> str:
+        """Semgrep's size cap from config.max_file_bytes; 0 disables it."""
+        return str(self.config.max_file_bytes or 0)
+
+    def _record_oversized_files(
+        self, target_path: str, scan_ignore: ScanIgnore
+    ) -> None:
+        """Record the files Semgrep's --max-target-bytes will skip as scan errors."""
+        target = Path(target_path)
+        candidates = [target] if target.is_file() else target.rglob("*")
+        for file_path in candidates:
+            if (
+                file_path.is_file()
+                and file_path.suffix.lower() in self.config.code_extensions
+                and not set(file_path.parts) & self.config.skip_patterns
+                and not scan_ignore.is_ignored(file_path)
+                and self.exceeds_size_cap(file_path)
+            ):
+                self.record_size_skip(file_path)
+
+    def _semgrep_jobs(self) -> int:
+        """Resolve Semgrep worker count from config, defaulting to available CPUs."""
+        return self.config.threads or os.cpu_count() or 1
+
+    @staticmethod
+    def _sort_findings(findings: list[dict[str, Any]]) -> list[dict[str, Any]]:
+        """Order findings deterministically so output does not depend on --jobs."""
+        return sorted(
+            findings,
+            key=lambda f: (
+                f["file_path"],
+                f["line_number"],
+                f["perf_type"],
+                f["line_content"],
+            ),
+        )
 
     def analyze(self, target_path: Optional[str] = None) -> Any:
         """
@@ -509,9 +682,21 @@ class SemgrepAnalyzer(BaseAnalyzer):
                 result.metadata["info"] = "Semgrep not available - analysis skipped"
                 return self.complete_analysis(result)
 
+            self.scan_errors = []
+            self.files_skipped = 0
+            # One walk for .scannerignore files, shared by both passes
+            scan_ignore = ScanIgnore(analyze_path)
+            self._record_oversized_files(analyze_path, scan_ignore)
+
             # Pass the DIRECTORY to Semgrep, not individual files
             # Semgrep will handle all file discovery and exclusions
-            raw_findings = self._run_semgrep_on_directory(analyze_path)
+            # Semgrep discovers the files itself, so there is no total to report
+            self._files_total = None
+            self._files_done = self._findings_so_far = 0
+            self.emit_scan_event("started")
+            raw_findings = self._run_semgrep_on_directory(analyze_path, scan_ignore)
+            self._findings_so_far = len(raw_findings)
+            self.emit_scan_event("finished")
 
             # Convert to standardized format for BaseAnalyzer
             standardized_findings = []
@@ -529,12 +714,15 @@ class SemgrepAnalyzer(BaseAnalyzer):
                         "category": finding["category"],
                         "line_content": finding["line_content"],
                         "confidence": finding["confidence"],
+                        **_span(finding),
                     },
                 }
+                if finding.get("flow"):
+                    standardized["metadata"]["flow"] = finding["flow"]
                 standardized_findings.append(standardized)
 
             # Convert findings to Finding objects
-            self._add_findings_to_result(result, standardized_findings)
+            self._add_findings_to_result(result, standardized_findings, analyze_path)
 
             # Add comprehensive metadata (with empty file list since Semgrep handled discovery)
             result.metadata = {
@@ -544,15 +732,16 @@ class SemgrepAnalyzer(BaseAnalyzer):
                 "files_processed": len(
                     {f["file_path"] for f in raw_findings}
                 ),  # Unique files with findings
-                "files_skipped": 0,  # Semgrep handles this
+                "files_skipped": self.files_skipped,
                 "processing_errors": 0,
+                "scan_errors": self.scan_errors,
                 "total_findings": len(standardized_findings),
                 "severity_breakdown": self._calculate_severity_breakdown(
                     standardized_findings
                 ),
                 "analyzer_config": {
                     "max_files": self.config.max_files,
-                    "max_file_size_mb": self.config.max_file_size_mb,
+                    "max_file_bytes": self.config.max_file_bytes,
                     "extensions_count": len(self.config.code_extensions),
                     "skip_patterns_count": len(self.config.skip_patterns),
                 },
@@ -607,8 +796,11 @@ class SemgrepAnalyzer(BaseAnalyzer):
                     "category": finding["category"],
                     "line_content": finding["line_content"],
                     "confidence": finding["confidence"],
+                    **_span(finding),
                 },
             }
+            if finding.get("flow"):
+                standardized["metadata"]["flow"] = finding["flow"]
             standardized_findings.append(standardized)
 
         return standardized_findings
@@ -637,19 +829,11 @@ class SemgrepAnalyzer(BaseAnalyzer):
             "r/secrets",  # Hardcoded secrets detection
         ]
 
-        # Add custom Rust security rules if analyzing Rust code
-        import os
-
-        rust_rules_path = os.path.join(
-            os.path.dirname(__file__), "rules", "rust-security.yml"
-        )
-        if os.path.exists(rust_rules_path):
-            rulesets.append(rust_rules_path)
-            print(f"Added custom Rust rules: {rust_rules_path}", file=sys.stderr)
-
-        print(f"Using rulesets: {rulesets}", file=sys.stderr)
-
-        findings = self._run_semgrep_analysis(target_path, rulesets)
+        # Add custom Rust security rules and any compiled rule manifests
+        with self._rule_configs() as configs:
+            rulesets.extend(configs)
+            print(f"Using rulesets: {rulesets}", file=sys.stderr)
+            findings = self._run_semgrep_analysis(target_path, rulesets)
 
         # Convert to our standardized format for BaseAnalyzer
         standardized_findings = []
@@ -667,8 +851,11 @@ class SemgrepAnalyzer(BaseAnalyzer):
                     "category": finding["category"],
                     "line_content": finding["line_content"],
                     "confidence": finding["confidence"],
+                    **_span(finding),
                 },
             }
+            if finding.get("flow"):
+                standardized["metadata"]["flow"] = finding["flow"]
             standardized_findings.append(standardized)
 
         return standardized_findings
@@ -27,12 +27,28 @@ EXTENDS: Similar to BaseProfiler but for general analysis tools
 - Abstract interface for specific analysis implementations
 """
 
+import os
+import tempfile
 from abc import ABC, abstractmethod
 from dataclasses import dataclass, field
+from functools import partial
 from pathlib import Path
-from typing import Any, Optional
-
+from typing import Any, Callable, Optional
+
+from core.utils.git_blame import BlameCache
+from core.utils.git_staged import staged_line_ranges
+from core.utils.output_formatter import AnalysisResult
+from core.utils.progress import ScanEvent
+from core.utils.redaction import is_secret_finding, redact_snippet
+from core.utils.rust_symbols import find_rust_function
+from core.utils.test_code import TEST_ONLY_SEVERITY, TestCodeClassifier
+
+from .archive_utils import extract_archive
+from .false_positives import FalsePositiveStore, finding_fingerprint
+from .ignore_rules import ScanIgnore
 from .module_base import CIAnalysisModule
+from .report_cache import ReportCache, ReportKey
+from .rule_aliases import canonical_rule_id
 from .validation_rules import (
     FieldTypesRule,
     PathAndLineRules,
@@ -43,6 +59,42 @@ from .validation_rules import (
 )
 from .vendor_detector import VendorDetector
 
+# Extension used for in-memory sources when the language is given explicitly
+SOURCE_LANGUAGE_EXTENSIONS = {
+    "python": ".py",
+    "javascript": ".js",
+    "typescript": ".ts",
+    "java": ".java",
+    "csharp": ".cs",
+    "php": ".php",
+    "ruby": ".rb",
+    "go": ".go",
+    "kotlin": ".kt",
+    "swift": ".swift",
+    "rust": ".rs",
+    "cpp": ".cpp",
+    "c": ".c",
+}
+_EXTENSION_LANGUAGES = {
+    extension: language for language, extension in SOURCE_LANGUAGE_EXTENSIONS.items()
+}
+
+
+def _taint_summary(findings: list[Any], first: int, last: int) -> list[dict[str, str]]:
+    """Rule, first step and last step of each taint flow found on lines first..last."""
+    summary = []
+    for finding in findings:
+        flow = finding.evidence.get("flow")
+        if flow and first <= (finding.line_number or 0) <= last:
+            summary.append(
+                {
+                    "rule": finding.rule_id,
+                    "source": flow[0]["content"],
+                    "sink": flow[-1]["content"],
+                }
+            )
+    return summary
+
 
 @dataclass
 class AnalyzerConfig:
@@ -52,7 +104,13 @@ class AnalyzerConfig:
     target_path: str = "."
     output_format: str = "json"
     min_severity: str = "medium"
+    min_confidence: str = "low"
     summary_mode: bool = False
+    redact_secrets: bool = True  # Mask secret values in finding snippets
+    blame: bool = False  # Attach git blame commit/author/date to findings (slow)
+    line_ending: str = "lf"  # lf | crlf for text report output
+    # SARIF level per severity, overriding the defaults (e.g. medium -> error)
+    sarif_level_map: dict[str, str] = field(default_factory=dict)
 
     # File filtering
     code_extensions: set[str] = field(
@@ -114,11 +172,25 @@ class AnalyzerConfig:
         }
     )
 
+    # Rules tagged "opt-in" only report when one of their tags is enabled here
+    enabled_rule_tags: set[str] = field(default_factory=set)
+
+    # Rule ids never reported; legacy ids from rule_aliases are accepted
+    suppressed_rules: set[str] = field(default_factory=set)
+
+    # Declarative rule manifests (see analyzers/security/rules_dsl.py)
+    rule_manifests: list[str] = field(default_factory=list)
+
+    # JSON store of fingerprints marked as false positives; matches are hidden
+    false_positive_store: Optional[str] = None
+
     # Analysis settings
     max_files: Optional[int] = None
-    max_file_size_mb: int = 5
+    # Larger files are skipped and recorded in metadata["scan_errors"]; None = no cap
+    max_file_bytes: Optional[int] = 2 * 1024 * 1024
     batch_size: int = 200
     timeout_seconds: Optional[int] = None
+    threads: Optional[int] = None  # None = use available CPUs
 
     # Severity filtering
     severity_thresholds: dict[str, float] = field(
@@ -138,14 +210,18 @@ class AnalyzerConfig:
         """Validate configuration values."""
         if self.max_files is not None and self.max_files <= 0:
             raise ValueError("max_files must be positive")
-        if self.max_file_size_mb <= 0:
-            raise ValueError("max_file_size_mb must be positive")
+        if self.max_file_bytes is not None and self.max_file_bytes <= 0:
+            raise ValueError("max_file_bytes must be positive")
         if self.batch_size <= 0:
             raise ValueError("batch_size must be positive")
         if self.timeout_seconds is not None and self.timeout_seconds <= 0:
             raise ValueError("timeout_seconds must be positive")
+        if self.threads is not None and self.threads <= 0:
+            raise ValueError("threads must be positive")
 
-        valid_formats = {"json", "console", "summary"}
+        valid_formats = {
+            "json", "console", "summary", "html", "markdown", "sarif", "osv"
+        }
         if self.output_format not in valid_formats:
             raise ValueError(f"output_format must be one of: {valid_formats}")
 
@@ -153,6 +229,38 @@ class AnalyzerConfig:
         if self.min_severity not in valid_severities:
             raise ValueError(f"min_severity must be one of: {valid_severities}")
 
+        valid_line_endings = {"lf", "crlf"}
+        if self.line_ending not in valid_line_endings:
+            raise ValueError(f"line_ending must be one of: {valid_line_endings}")
+
+        valid_confidences = {"high", "medium", "low"}
+        if self.min_confidence not in valid_confidences:
+            raise ValueError(f"min_confidence must be one of: {valid_confidences}")
+
+        valid_sarif_levels = {"error", "warning", "note", "none"}
+        for severity, level in self.sarif_level_map.items():
+            if severity not in valid_severities | {"info"}:
+                raise ValueError(f"sarif_level_map has unknown severity {severity!r}")
+            if level not in valid_sarif_levels:
+                raise ValueError(
+                    f"sarif_level_map levels must be one of: {valid_sarif_levels}"
+                )
+
+
+@dataclass
+class ScanPlan:
+    """Files a scan would analyze, with the language and rules for each."""
+
+    analyzer: str
+    files: list[dict[str, Any]] = field(default_factory=list)
+
+    def to_dict(self) -> dict[str, Any]:
+        return {
+            "analyzer": self.analyzer,
+            "total_files": len(self.files),
+            "files": self.files,
+        }
+
 
 class BaseAnalyzer(CIAnalysisModule, ABC):
     """
@@ -188,6 +296,16 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
         self.files_processed = 0
         self.files_skipped = 0
         self.processing_errors = 0
+        # Files skipped for a reason the report should show, e.g. size cap
+        self.scan_errors: list[dict[str, Any]] = []
+        # Set while analyze() builds its file list, so each skip counts once
+        self._record_size_skips = False
+
+        # Receives ScanEvents while files are processed (e.g. a ProgressBar)
+        self.on_scan_event: Optional[Callable[[ScanEvent], None]] = None
+        self._files_total: Optional[int] = None
+        self._files_done = 0
+        self._findings_so_far = 0
 
         self.log_operation(
             "analyzer_initialized",
@@ -276,12 +394,9 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
 
         # Check file size
         try:
-            file_size_mb = file_path.stat().st_size / (1024 * 1024)
-            if file_size_mb > self.config.max_file_size_mb:
-                self.log_operation(
-                    "file_skipped_size",
-                    {"file": str(file_path), "size_mb": round(file_size_mb, 2)},
-                )
+            if self.exceeds_size_cap(file_path):
+                if self._record_size_skips:
+                    self.record_size_skip(file_path)
                 return False
         except (OSError, FileNotFoundError):
             return False
@@ -302,12 +417,41 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
 
         return True
 
-    def scan_directory(self, target_path: str) -> list[Path]:
+    def exceeds_size_cap(self, file_path: Path) -> bool:
+        """Return True if file_path is over max_file_bytes."""
+        cap = self.config.max_file_bytes
+        return cap is not None and file_path.stat().st_size > cap
+
+    def record_size_skip(self, file_path: Path) -> None:
+        """
+        Record an oversized file as a scan error.
+
+        Call once per file, where a scan builds its final file list; discovery
+        passes such as plan() and the scan_cached key only check the cap.
+        """
+        cap = self.config.max_file_bytes
+        size = file_path.stat().st_size
+        self.files_skipped += 1
+        self.scan_errors.append(
+            {
+                "file": str(file_path),
+                "error": f"skipped: {size} bytes exceeds max_file_bytes ({cap})",
+                "size_bytes": size,
+            }
+        )
+        self.log_operation(
+            "file_skipped_size", {"file": str(file_path), "size_bytes": size}
+        )
+
+    def scan_directory(
+        self, target_path: str, scan_ignore: Optional[ScanIgnore] = None
+    ) -> list[Path]:
         """
         Scan directory for files matching analyzer criteria.
 
         Args:
             target_path: Directory to scan
+            scan_ignore: `.scannerignore` rules for target_path, if already loaded
 
         Returns
         -------
@@ -315,9 +459,10 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
         """
         target = Path(target_path)
         files_to_scan = []
+        scan_ignore = scan_ignore or ScanIgnore(target)
 
         if target.is_file():
-            if self.should_scan_file(target):
+            if not scan_ignore.is_ignored(target) and self.should_scan_file(target):
                 files_to_scan.append(target)
         elif target.is_dir():
             for file_path in target.rglob("*"):
@@ -330,7 +475,11 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
                     )
                     break
 
-                if file_path.is_file() and self.should_scan_file(file_path):
+                if (
+                    file_path.is_file()
+                    and not scan_ignore.is_ignored(file_path)
+                    and self.should_scan_file(file_path)
+                ):
                     files_to_scan.append(file_path)
 
         self.log_operation(
@@ -340,6 +489,160 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
 
         return files_to_scan
 
+    def applicable_rules(self, file_path: Path) -> list[str]:
+        """
+        Rule sets this analyzer would run on file_path.
+
+        Empty means every check of the analyzer applies to every file it scans;
+        analyzers that choose rules per file override this.
+        """
+        return []
+
+    def plan(self, paths: Optional[list[str]] = None) -> ScanPlan:
+        """
+        List the files a scan would analyze, without running any analysis.
+
+        Discovery is the same as analyze(): extensions, skip patterns, size and
+        vendor checks, `.scannerignore` and max_files all apply.
+
+        Args:
+            paths: Files or directories to plan (uses config.target_path if None)
+
+        Returns
+        -------
+            ScanPlan with one entry per file, in discovery order
+        """
+        plan = ScanPlan(analyzer=self.module_name)
+        for target in paths or [self.config.target_path]:
+            for file_path in self.scan_directory(target):
+                suffix = file_path.suffix.lower()
+                plan.files.append(
+                    {
+                        "file_path": str(file_path),
+                        "language": _EXTENSION_LANGUAGES.get(
+                            suffix, suffix.lstrip(".") or "unknown"
+                        ),
+                        "rules": self.applicable_rules(file_path),
+                    }
+                )
+        return plan
+
+    def toolchain_version(self) -> str:
+        """Version of the external tool this analyzer drives ("" if none)."""
+        return ""
+
+    def cache_inputs(self, target_path: str) -> list[Path]:
+        """
+        Files whose contents can change an analyze() of target_path.
+
+        The default is the analyzer's own file discovery; analyzers that let
+        their tool discover files override this to cover what the tool reads.
+        """
+        return self.scan_directory(target_path)
+
+    def scan_cached(
+        self, paths: Optional[list[str]] = None, cache_dir: str = ".scan-cache"
+    ) -> Any:
+        """
+        Analyze paths, reusing the stored report when no input has changed.
+
+        The whole report is keyed on the scanner and tool versions, the
+        config, the contents of every file in cache_inputs(), of the rule files
+        applied to them and of the false-positive store (see report_cache.py),
+        so marking a false positive also invalidates it. A hit returns the
+        stored report without analyzing anything; a miss runs analyze() and
+        stores a successful result. `metadata["report_cache"]` is "hit" or
+        "miss". A miss also refreshes the per-function taint summaries of Rust
+        inputs, counted in `metadata["function_summaries"]`.
+
+        Args:
+            paths: Files or directories to analyze (uses config.target_path if None)
+            cache_dir: Directory holding the cached report
+
+        Returns
+        -------
+            AnalysisResult object with findings and metadata
+        """
+        targets = paths or [self.config.target_path]
+        key = ReportKey(self.module_name, self.toolchain_version(), self.config)
+        cache = ReportCache(cache_dir, self.module_name)
+        rule_files: set[Path] = set()
+        rust_files: list[Path] = []
+        store = self.config.false_positive_store
+        if store and Path(store).is_file():
+            key.add_file("false_positive_store", Path(store))
+        cache_files = {cache.path.resolve(), cache.functions_path.resolve()}
+        for target in targets:
+            key.add_text(str(target))
+            for file_path in sorted(self.cache_inputs(target)):
+                # A cache directory inside the target must not key itself
+                if file_path.resolve() in cache_files:
+                    continue
+                key.add_file(file_path.as_posix(), file_path)
+                if file_path.suffix == ".rs":
+                    rust_files.append(file_path)
+                rule_files.update(
+                    Path(rule)
+                    for rule in self.applicable_rules(file_path)
+                    if Path(rule).is_file()
+                )
+        for rule_file in sorted(rule_files):
+            key.add_file(rule_file.as_posix(), rule_file)
+
+        digest = key.hexdigest()
+        cached = cache.load(digest)
+        if cached is not None:
+            result = AnalysisResult.from_dict(cached)
+            result.metadata["report_cache"] = "hit"
+            return result
+
+        result = self.analyze(targets[0])
+        for target in targets[1:]:
+            extra = self.analyze(target)
+            result.findings.extend(extra.findings)
+            result.metadata["scan_errors"] = [
+                *result.metadata.get("scan_errors", []),
+                *extra.metadata.get("scan_errors", []),
+            ]
+            if not extra.success:
+                result.set_error(extra.error_message)
+        result.metadata["report_cache"] = "miss"
+        if result.success:
+            result.metadata["function_summaries"] = self._summarize_functions(
+                cache, rust_files, result.findings
+            )
+            report = result.to_dict(min_severity="info")
+            # One list in scan order, so a hit reproduces the result exactly
+            report["findings"] = [finding.to_dict() for finding in result.findings]
+            report.pop("test_only_findings", None)
+            cache.store(digest, report)
+        return result
+
+    @staticmethod
+    def _summarize_functions(
+        cache: ReportCache, rust_files: list[Path], findings: list[Any]
+    ) -> dict[str, int]:
+        """
+        Refresh the cached taint summary of every function in rust_files.
+
+        Unchanged functions keep their stored summary (see
+        ReportCache.function_summaries).
+        """
+        counts = {"computed": 0, "reused": 0}
+        for file_path in rust_files:
+            in_file = [
+                f
+                for f in findings
+                if f.file_path and Path(f.file_path).resolve() == file_path.resolve()
+            ]
+            source = file_path.read_text(encoding="utf-8", errors="ignore")
+            summaries, computed = cache.function_summaries(
+                file_path.as_posix(), source, partial(_taint_summary, in_file)
+            )
+            counts["computed"] += computed
+            counts["reused"] += len(summaries) - computed
+        return counts
+
     def process_files_batch(self, files: list[Path]) -> list[dict[str, Any]]:
         """
         Process files in batches for memory efficiency.
@@ -352,11 +655,18 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
             Combined findings from all files
         """
         all_findings = []
+        self._files_total = len(files)
+        self._files_done = self._findings_so_far = 0
+        self.emit_scan_event("started")
 
         for i in range(0, len(files), self.config.batch_size):
             batch = files[i : i + self.config.batch_size]
             batch_findings = self._process_batch(batch)
             all_findings.extend(batch_findings)
+            # Covers _process_batch overrides that do not report per file
+            self._files_done = i + len(batch)
+            self._findings_so_far = len(all_findings)
+            self.emit_scan_event("progress")
 
             self.log_operation(
                 "batch_processed",
@@ -367,8 +677,22 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
                 },
             )
 
+        self.emit_scan_event("finished")
         return all_findings
 
+    def emit_scan_event(self, kind: str) -> None:
+        """Publish the current scan progress to on_scan_event, if set."""
+        if self.on_scan_event is None:
+            return
+        self.on_scan_event(
+            ScanEvent(
+                kind=kind,
+                files_done=self._files_done,
+                files_total=self._files_total,
+                findings=self._findings_so_far,
+            )
+        )
+
     def _process_batch(self, batch: list[Path]) -> list[dict[str, Any]]:
         """Process a single batch of files."""
         batch_findings = []
@@ -379,11 +703,15 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
                 file_findings = self.analyze_target(str(file_path))
                 batch_findings.extend(file_findings)
                 self.files_processed += 1
+                self._findings_so_far += len(file_findings)
 
             except Exception as e:
                 self.processing_errors += 1
                 self.logger.warning(f"Error processing {file_path}: {e}")
 
+            self._files_done += 1
+            self.emit_scan_event("progress")
+
         return batch_findings
 
     def analyze(self, target_path: Optional[str] = None) -> Any:
@@ -398,13 +726,19 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
             AnalysisResult object with findings and metadata
         """
         self.start_analysis()
+        self.scan_errors = []
+        self.files_skipped = 0
 
         analyze_path = target_path or self.config.target_path
         result = self.create_result("analysis")
 
         try:
-            # Scan for files to analyze
-            files_to_analyze = self.scan_directory(analyze_path)
+            # Scan for files to analyze, recording size-cap skips for this pass only
+            self._record_size_skips = True
+            try:
+                files_to_analyze = self.scan_directory(analyze_path)
+            finally:
+                self._record_size_skips = False
 
             if not files_to_analyze:
                 # Add info as metadata instead of calling non-existent add_info method
@@ -415,7 +749,7 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
             all_findings = self.process_files_batch(files_to_analyze)
 
             # Convert findings to Finding objects
-            self._add_findings_to_result(result, all_findings)
+            self._add_findings_to_result(result, all_findings, analyze_path)
 
             # Add comprehensive metadata
             self._add_metadata_to_result(
@@ -428,25 +762,242 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
 
         return self.complete_analysis(result)
 
+    def analyze_source(
+        self, source: str, virtual_path: str, language: Optional[str] = None
+    ) -> Any:
+        """
+        Analyze in-memory source as if it were the file at virtual_path.
+
+        Used for editor and pipe integration (`--stdin --path file.rs`). The
+        source is written to a scratch file with the same name, so tools that
+        infer language from the extension behave as they would on disk, and
+        every finding is reported against virtual_path.
+
+        Args:
+            source: File contents to analyze
+            virtual_path: Path to report findings against
+            language: Overrides the language implied by virtual_path's extension
+
+        Returns
+        -------
+            AnalysisResult object with findings and metadata
+        """
+        self.start_analysis()
+        result = self.create_result("analysis")
+
+        try:
+            with tempfile.TemporaryDirectory(prefix="scan-source-") as scratch:
+                source_file = Path(scratch) / Path(virtual_path).name
+                if language:
+                    source_file = source_file.with_suffix(
+                        SOURCE_LANGUAGE_EXTENSIONS[language]
+                    )
+                source_file.write_text(source, encoding="utf-8")
+                findings = self.analyze_target(str(source_file))
+
+            # Only one file was analyzed, so every finding belongs to it
+            for finding_data in findings:
+                finding_data["file_path"] = virtual_path
+
+            self._add_findings_to_result(result, findings)
+            self._add_metadata_to_result(
+                result, virtual_path, [Path(virtual_path)], findings
+            )
+
+        except Exception as e:
+            result.set_error(f"{self.analyzer_type} analysis failed: {str(e)}")
+            self.logger.error(f"Analysis failed: {e}")
+
+        return self.complete_analysis(result)
+
+    def scan_function(self, path: str, function_name: str) -> list[Any]:
+        """
+        Analyze one Rust function and return only the findings inside it.
+
+        The whole file is analyzed, so rules that need the signature (such as
+        warp handler taint sources) still see it; findings outside the
+        function's lines are then dropped. function_name may be qualified by
+        its impl, trait or module, e.g. `Server::handle`.
+
+        Args:
+            path: Rust source file containing the function
+            function_name: Name of the `fn`, optionally `::`-qualified
+
+        Returns
+        -------
+            Finding objects located in the function, in analyzer order
+
+        Raises
+        ------
+            ValueError: If path is not Rust, or the function is missing or ambiguous
+        """
+        source_file = Path(path)
+        if source_file.suffix != ".rs":
+            raise ValueError(f"scan_function supports Rust files only: {path}")
+        first_line, last_line = find_rust_function(
+            source_file.read_text(encoding="utf-8", errors="ignore"), function_name
+        )
+
+        findings = [
+            finding
+            for finding in self.analyze_target(str(source_file))
+            if first_line <= (finding.get("line_number") or 0) <= last_line
+        ]
+        result = self.create_result("analysis")
+        self._add_findings_to_result(result, findings)
+        return result.findings
+
+    def scan_staged(self, repo_root: str = ".") -> Any:
+        """
+        Analyze only the files staged in git, keeping findings on staged lines.
+
+        Meant for pre-commit hooks: files come from `git diff --cached`, and
+        findings outside the staged hunks are dropped so existing issues in a
+        touched file do not block the commit. The working-tree copy of each
+        file is scanned, so unstaged edits should be stashed first (as the
+        pre-commit framework does) for exact line matching.
+
+        Args:
+            repo_root: Root of the git work tree, or a subdirectory to limit the scan to
+
+        Returns
+        -------
+            AnalysisResult object with findings on staged lines only
+        """
+        self.start_analysis()
+        self.scan_errors = []
+        self.files_skipped = 0
+        result = self.create_result("analysis")
+
+        try:
+            staged = staged_line_ranges(repo_root)
+            candidates = [Path(repo_root) / name for name in staged]
+            self._record_size_skips = True
+            try:
+                files = [
+                    f for f in candidates if f.is_file() and self.should_scan_file(f)
+                ]
+            finally:
+                self._record_size_skips = False
+            root = Path(repo_root).resolve()
+
+            def on_staged_line(finding: dict[str, Any]) -> bool:
+                path = Path(finding.get("file_path", "")).resolve()
+                relative = Path(os.path.relpath(path, root)).as_posix()
+                return finding.get("line_number") in staged.get(relative, set())
+
+            raw_findings = self.process_files_batch(files) if files else []
+            findings = [f for f in raw_findings if on_staged_line(f)]
+            self._add_findings_to_result(result, findings, repo_root)
+            self._add_metadata_to_result(result, repo_root, files, findings)
+            result.metadata["staged_files"] = len(staged)
+        except Exception as e:
+            result.set_error(f"{self.analyzer_type} staged scan failed: {str(e)}")
+            self.logger.error(f"Staged scan failed: {e}")
+
+        return self.complete_analysis(result)
+
+    def analyze_archive(self, archive_path: str) -> Any:
+        """
+        Extract an archive to a scratch directory, analyze it, and clean up.
+
+        Extraction rejects members that would escape the scratch directory.
+        Finding paths are reported relative to the archive root.
+
+        Args:
+            archive_path: `.tar.gz`, `.tgz`, `.tar` or `.zip` file to analyze
+
+        Returns
+        -------
+            AnalysisResult object with findings and metadata
+        """
+        with tempfile.TemporaryDirectory(prefix="scan-archive-") as scratch:
+            root = extract_archive(archive_path, scratch)
+            result = self.analyze(str(root))
+
+            for finding in result.findings:
+                if not finding.file_path:
+                    continue
+                resolved = Path(finding.file_path).resolve()
+                if resolved.is_relative_to(root):
+                    finding.file_path = resolved.relative_to(root).as_posix()
+
+        result.target_path = archive_path
+        result.metadata["target_path"] = archive_path
+        result.metadata["archive"] = True
+        return result
+
     def _add_findings_to_result(
-        self, result: Any, findings: list[dict[str, Any]]
+        self,
+        result: Any,
+        findings: list[dict[str, Any]],
+        scan_root: Optional[str] = None,
     ) -> None:
-        """Convert raw findings to Finding objects and add to result."""
+        """
+        Convert raw findings to Finding objects and add to result.
+
+        scan_root is the target actually scanned; relative finding paths and
+        test-code classification are judged against it. Defaults to the
+        configured target_path.
+        """
+        scan_root = scan_root or self.config.target_path
         finding_id = 1
+        confidence_order = {"high": 0, "medium": 1, "low": 2}
+        max_confidence_level = confidence_order[self.config.min_confidence]
+        false_positives = (
+            FalsePositiveStore(self.config.false_positive_store)
+            if self.config.false_positive_store
+            else None
+        )
+        blame_cache = BlameCache() if self.config.blame else None
+        suppressed_rules = {canonical_rule_id(r) for r in self.config.suppressed_rules}
+        test_code = TestCodeClassifier(scan_root)
 
         for finding_data in findings:
+            metadata = finding_data.get("metadata", {})
+            confidence = metadata.get("confidence", "high")
+            if confidence_order.get(confidence, 0) > max_confidence_level:
+                continue
+
+            if metadata.get("check_id"):
+                metadata["check_id"] = canonical_rule_id(metadata["check_id"])
+                if metadata["check_id"] in suppressed_rules:
+                    continue
+
+            fingerprint = finding_fingerprint(finding_data)
+            if false_positives is not None and fingerprint in false_positives:
+                continue
+
+            evidence = {**metadata, "fingerprint": fingerprint}
+            if self.config.redact_secrets and evidence.get("line_content"):
+                evidence["line_content"] = redact_snippet(
+                    evidence["line_content"], is_secret_finding(evidence)
+                )
+            if blame_cache is not None:
+                evidence.update(
+                    self._blame_finding(blame_cache, finding_data, scan_root)
+                )
+
             try:
+                # Secrets in tests/examples stay visible, one severity level down
+                severity = finding_data["severity"]
+                if is_secret_finding(evidence) and test_code.is_test_code(
+                    finding_data["file_path"] or "", finding_data["line_number"]
+                ):
+                    evidence["test_only"] = True
+                    severity = TEST_ONLY_SEVERITY.get(severity, severity)
+
                 # Create Finding object - require all fields to be present
                 finding = self.ResultFormatter.create_finding(
                     self.ResultFormatter.FindingInput(
                         finding_id=f"{self.analyzer_type.upper()}{finding_id:03d}",
                         title=finding_data["title"],
                         description=finding_data["description"],
-                        severity=finding_data["severity"],
+                        severity=severity,
                         file_path=finding_data["file_path"],
                         line_number=finding_data["line_number"],
                         recommendation=finding_data["recommendation"],
-                        evidence=finding_data.get("metadata", {}),
+                        evidence=evidence,
                     )
                 )
 
@@ -465,6 +1016,20 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
                 self.logger.error(f"Error creating finding {finding_id}: {e}")
                 raise
 
+    def _blame_finding(
+        self, blame_cache: BlameCache, finding_data: dict[str, Any], scan_root: str
+    ) -> dict[str, str]:
+        """Look up git blame for a finding's line; empty if not under git."""
+        file_path = finding_data.get("file_path")
+        line_number = finding_data.get("line_number")
+        if not file_path or not line_number:
+            return {}
+
+        path = Path(file_path)
+        if not path.is_absolute() and not path.exists():
+            path = Path(scan_root) / path
+        return blame_cache.blame_line(path, int(line_number)) or {}
+
     def _add_metadata_to_result(
         self,
         result: Any,
@@ -482,11 +1047,12 @@ class BaseAnalyzer(CIAnalysisModule, ABC):
             "files_processed": self.files_processed,
             "files_skipped": self.files_skipped,
             "processing_errors": self.processing_errors,
+            "scan_errors": self.scan_errors,
             "total_findings": len(findings),
             "severity_breakdown": self._calculate_severity_breakdown(findings),
             "analyzer_config": {
                 "max_files": self.config.max_files,
-                "max_file_size_mb": self.config.max_file_size_mb,
+                "max_file_bytes": self.config.max_file_bytes,
                 "extensions_count": len(self.config.code_extensions),
                 "skip_patterns_count": len(self.config.skip_patterns),
             },
@@ -0,0 +1,87 @@
+#!/usr/bin/env python3
+"""
+Archive Extraction - safe unpacking of `.tar.gz`/`.tgz`/`.tar`/`.zip` inputs.
+
+PURPOSE: Let CI scan downloaded artifacts directly. Archives are untrusted
+input, so extraction refuses any member that would land outside the target
+directory (zip-slip), and only regular files and directories are written;
+symlinks, hard links and device nodes are skipped.
+"""
+
+import shutil
+import stat
+import tarfile
+import zipfile
+from pathlib import Path, PurePosixPath
+from typing import Union
+
+ARCHIVE_SUFFIXES = (".tar.gz", ".tgz", ".tar", ".zip")
+
+
+def is_archive(path: Union[str, Path]) -> bool:
+    """Return True if the path names a supported archive file."""
+    name = Path(path).name.lower()
+    return Path(path).is_file() and name.endswith(ARCHIVE_SUFFIXES)
+
+
+def _safe_destination(root: Path, member_name: str) -> Path:
+    """Resolve a member path under root, rejecting traversal and absolute paths."""
+    member = PurePosixPath(member_name.replace("\\", "/"))
+    destination = (root / member).resolve()
+    if (
+        member.is_absolute()
+        or ".." in member.parts
+        or not destination.is_relative_to(root)
+    ):
+        raise ValueError(
+            f"Refusing archive member outside extraction root: {member_name}"
+        )
+    return destination
+
+
+def extract_archive(
+    archive_path: Union[str, Path], destination: Union[str, Path]
+) -> Path:
+    """
+    Extract a supported archive into destination and return the resolved root.
+
+    Raises
+    ------
+        ValueError: If the format is unsupported or a member escapes the root
+    """
+    archive = Path(archive_path)
+    root = Path(destination).resolve()
+    root.mkdir(parents=True, exist_ok=True)
+
+    if archive.name.lower().endswith(".zip"):
+        with zipfile.ZipFile(archive) as zf:
+            for info in zf.infolist():
+                target = _safe_destination(root, info.filename)
+                if info.is_dir():
+                    target.mkdir(parents=True, exist_ok=True)
+                    continue
+                # Zip stores the Unix mode in the high 16 bits
+                if stat.S_ISLNK(info.external_attr >> 16):
+                    continue
+                target.parent.mkdir(parents=True, exist_ok=True)
+                with zf.open(info) as src, open(target, "wb") as dst:
+                    shutil.copyfileobj(src, dst)
+    elif archive.name.lower().endswith((".tar.gz", ".tgz", ".tar")):
+        with tarfile.open(archive) as tf:
+            for member in tf.getmembers():
+                target = _safe_destination(root, member.name)
+                if member.isdir():
+                    target.mkdir(parents=True, exist_ok=True)
+                    continue
+                if not member.isfile():
+                    continue
+                target.parent.mkdir(parents=True, exist_ok=True)
+                src = tf.extractfile(member)
+                if src is None:
+                    continue
+                with src, open(target, "wb") as dst:
+                    shutil.copyfileobj(src, dst)
+    else:
+        raise ValueError(f"Unsupported archive format: {archive.name}")
+
+    return root
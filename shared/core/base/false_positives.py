@@ -0,0 +1,190 @@
+#!/usr/bin/env python3
+"""
+False Positive Store - persistent triage annotations for findings.
+
+PURPOSE: Let users mark a finding as a false positive once and have later
+scans hide it. Annotations are keyed by a fingerprint that survives line
+shifts, stored as JSON, and summarised per rule so maintainers can see which
+rules need tuning.
+
+FINGERPRINT:
+- Rule identifier (`metadata.check_id` resolved through rule aliases,
+  falling back to the finding title)
+- File path as reported by the analyzer
+- Matched line content with whitespace collapsed
+- Hash of the secret value, for secret scanners that report one
+
+Line numbers are deliberately excluded so unrelated edits above a finding
+do not invalidate its annotation. detect-secrets reports no line content, so
+without the secret hash every secret of one type in a file would share a
+fingerprint.
+
+BASELINE: `generate_suppressions` writes a store acknowledging every existing
+finding below a severity, so a legacy repo can adopt the scanner and block
+new high-severity issues while the older, lower ones are worked down.
+
+TRIAGE: `record_triage_decisions` writes per-finding review decisions into
+the same store; it is the headless back end for an interactive triage UI.
+"""
+
+import hashlib
+import json
+from collections import Counter
+from datetime import datetime
+from pathlib import Path
+from typing import Any, Union
+
+from .rule_aliases import canonical_rule_id
+
+# Lower rank is more severe
+_SEVERITY_RANK = {"critical": 0, "high": 1, "medium": 2, "low": 3, "info": 4}
+
+# Triage decisions that hide a finding, with the reason recorded for each
+_HIDING_DECISIONS = {
+    "false-positive": "triage: false positive",
+    "suppress": "triage: suppressed",
+}
+TRIAGE_DECISIONS = ("accept", *_HIDING_DECISIONS)
+
+
+def finding_fingerprint(finding: dict[str, Any]) -> str:
+    """Return a stable fingerprint for a raw analyzer finding."""
+    metadata = finding.get("metadata", {})
+    rule = canonical_rule_id(metadata.get("check_id") or finding.get("title", ""))
+    line_content = " ".join(str(metadata.get("line_content", "")).split())
+    parts = [rule, str(finding.get("file_path", "")), line_content]
+    if metadata.get("hashed_secret"):
+        parts.append(str(metadata["hashed_secret"]))
+    key = "\0".join(parts)
+    return hashlib.sha256(key.encode("utf-8")).hexdigest()[:16]
+
+
+class FalsePositiveStore:
+    """JSON-backed set of fingerprints marked as false positives."""
+
+    def __init__(self, path: Union[str, Path]):
+        self.path = Path(path)
+        self.entries: dict[str, dict[str, Any]] = {}
+        if self.path.is_file():
+            try:
+                data = json.loads(self.path.read_text(encoding="utf-8"))
+            except (OSError, json.JSONDecodeError) as e:
+                message = f"Invalid false positive store {self.path}: {e}"
+                raise ValueError(message) from e
+            self.entries = data.get("false_positives", {})
+
+    def __contains__(self, fingerprint: str) -> bool:
+        return fingerprint in self.entries
+
+    def mark(self, fingerprint: str, reason: str, rule: str = "") -> None:
+        """Record a fingerprint as a false positive and persist the store."""
+        self.entries[fingerprint] = {
+            "reason": reason,
+            "rule": canonical_rule_id(rule) if rule else "",
+            "marked_at": datetime.now().isoformat(),
+        }
+        self.save()
+
+    def save(self) -> None:
+        """Write the store to disk."""
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        payload = {"version": 1, "false_positives": self.entries}
+        self.path.write_text(json.dumps(payload, indent=2) + "\n", encoding="utf-8")
+
+    def stats(self) -> list[dict[str, Any]]:
+        """Count annotations per rule, most false positives first."""
+        counts = Counter(
+            canonical_rule_id(entry.get("rule") or "unknown")
+            for entry in self.entries.values()
+        )
+        ranked = sorted(counts.items(), key=lambda item: (-item[1], item[0]))
+        return [{"rule": rule, "false_positives": count} for rule, count in ranked]
+
+
+def generate_suppressions(
+    result: Any, below: str, path: Union[str, Path]
+) -> FalsePositiveStore:
+    """
+    Suppress every finding in result less severe than below, in the store at path.
+
+    Entries already in the store are kept. The file is the same store that
+    `false_positive_store` / `--fp-store` reads back.
+
+    Args:
+        result: AnalysisResult whose findings are acknowledged
+        below: Severity threshold; findings at or above it stay reported
+        path: Store file to create or extend
+
+    Returns
+    -------
+        The saved store
+    """
+    if below not in _SEVERITY_RANK:
+        raise ValueError(f"below must be one of: {list(_SEVERITY_RANK)}")
+
+    store = FalsePositiveStore(path)
+    marked_at = datetime.now().isoformat()
+    for finding in result.findings:
+        severity = finding.severity.value
+        fingerprint = finding.evidence.get("fingerprint")
+        if not fingerprint or _SEVERITY_RANK[severity] <= _SEVERITY_RANK[below]:
+            continue
+        store.entries.setdefault(
+            fingerprint,
+            {
+                "reason": f"baseline: existing {severity} finding",
+                "rule": finding.evidence.get("check_id") or finding.title,
+                "marked_at": marked_at,
+            },
+        )
+    store.save()
+    return store
+
+
+def record_triage_decisions(
+    result: Any, decisions: dict[str, str], path: Union[str, Path]
+) -> FalsePositiveStore:
+    """
+    Write triage decisions for findings in result to the store at path.
+
+    `false-positive` and `suppress` hide the finding from later scans;
+    `accept` keeps it reported, removing any earlier entry for it. Findings
+    without a decision are left as they are. Everything is validated before
+    the store is written.
+
+    Args:
+        result: AnalysisResult the decisions were made on
+        decisions: Decision per finding fingerprint, one of TRIAGE_DECISIONS
+        path: Store file to create or update
+
+    Returns
+    -------
+        The saved store
+
+    Raises
+    ------
+        ValueError: If a decision is unknown or names a fingerprint not in result
+    """
+    findings = {f.evidence.get("fingerprint"): f for f in result.findings}
+    for fingerprint, decision in decisions.items():
+        if decision not in TRIAGE_DECISIONS:
+            raise ValueError(
+                f"Unknown triage decision {decision!r}; expected {TRIAGE_DECISIONS}"
+            )
+        if fingerprint not in findings:
+            raise ValueError(f"No finding with fingerprint {fingerprint!r}")
+
+    store = FalsePositiveStore(path)
+    marked_at = datetime.now().isoformat()
+    for fingerprint, decision in decisions.items():
+        if decision == "accept":
+            store.entries.pop(fingerprint, None)
+            continue
+        finding = findings[fingerprint]
+        store.entries[fingerprint] = {
+            "reason": _HIDING_DECISIONS[decision],
+            "rule": finding.evidence.get("check_id") or finding.title,
+            "marked_at": marked_at,
+        }
+    store.save()
+    return store
@@ -0,0 +1,157 @@
+#!/usr/bin/env python3
+"""
+Scan Ignore Rules - `.scannerignore` support for analyzers.
+
+PURPOSE: Let teams check path exclusions into a repository without a full
+config file. `.scannerignore` files use gitignore syntax and apply to the
+directory they live in and everything below it.
+
+SUPPORTED SYNTAX:
+- Blank lines and `#` comments are ignored
+- `*`, `?`, `[...]` and `**` globs
+- Trailing `/` matches directories only
+- Leading or embedded `/` anchors the pattern to the ignore file's directory
+- Leading `!` re-includes a previously ignored path
+"""
+
+import re
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Union
+
+IGNORE_FILENAME = ".scannerignore"
+
+
+@dataclass
+class IgnorePattern:
+    """Single compiled line from a `.scannerignore` file."""
+
+    base: Path
+    regex: re.Pattern
+    negated: bool
+    dir_only: bool
+
+
+def _glob_to_regex(glob: str) -> str:
+    """Translate a gitignore glob into a regex fragment matched against posix paths."""
+    parts = []
+    i = 0
+    while i < len(glob):
+        if glob.startswith("**/", i):
+            parts.append("(?:.*/)?")
+            i += 3
+        elif glob.startswith("**", i):
+            parts.append(".*")
+            i += 2
+        elif glob[i] == "*":
+            parts.append("[^/]*")
+            i += 1
+        elif glob[i] == "?":
+            parts.append("[^/]")
+            i += 1
+        elif glob[i] == "[" and "]" in glob[i + 1 :]:
+            end = glob.index("]", i + 1)
+            body = glob[i + 1 : end].replace("\\", "\\\\")
+            if body.startswith("!"):
+                body = "^" + body[1:]
+            parts.append(f"[{body}]")
+            i = end + 1
+        else:
+            parts.append(re.escape(glob[i]))
+            i += 1
+    return "".join(parts)
+
+
+def parse_ignore_file(ignore_file: Path) -> list[IgnorePattern]:
+    """Compile the patterns of one `.scannerignore` file."""
+    base = ignore_file.parent.resolve()
+    patterns = []
+
+    for raw_line in ignore_file.read_text(encoding="utf-8").splitlines():
+        line = raw_line.rstrip()
+        if not line or line.startswith("#"):
+            continue
+
+        negated = line.startswith("!")
+        if negated:
+            line = line[1:]
+        elif line.startswith("\\"):
+            line = line[1:]
+
+        dir_only = line.endswith("/")
+        line = line.rstrip("/")
+        if not line:
+            continue
+
+        anchored = "/" in line
+        line = line.lstrip("/")
+        prefix = "" if anchored else "(?:.*/)?"
+        patterns.append(
+            IgnorePattern(
+                base=base,
+                regex=re.compile(prefix + _glob_to_regex(line)),
+                negated=negated,
+                dir_only=dir_only,
+            )
+        )
+
+    return patterns
+
+
+class ScanIgnore:
+    """
+    Merged `.scannerignore` rules discovered under a scan root.
+
+    Finding the ignore files walks the whole tree, so build one per scan and
+    share it. A single-file root only reads the ignore file beside it.
+    """
+
+    def __init__(self, root: Union[str, Path]):
+        root_path = Path(root).resolve()
+        if root_path.is_dir():
+            self.root = root_path
+            # Parents before children so nested files can override their ancestors
+            ignore_files = sorted(
+                root_path.rglob(IGNORE_FILENAME),
+                key=lambda p: (len(p.parts), str(p)),
+            )
+        else:
+            self.root = root_path.parent
+            ignore_files = [self.root / IGNORE_FILENAME]
+        self.patterns = [
+            pattern
+            for ignore_file in ignore_files
+            if ignore_file.is_file()
+            for pattern in parse_ignore_file(ignore_file)
+        ]
+
+    def is_ignored(self, path: Union[str, Path]) -> bool:
+        """Return True if the path or any of its parent directories is ignored."""
+        if not self.patterns:
+            return False
+
+        resolved = Path(path).resolve()
+        try:
+            parts = resolved.relative_to(self.root).parts
+        except ValueError:
+            return False
+
+        for depth in range(1, len(parts) + 1):
+            candidate = self.root.joinpath(*parts[:depth])
+            is_dir = depth < len(parts) or resolved.is_dir()
+            if self._matches(candidate, is_dir):
+                return True
+        return False
+
+    def _matches(self, candidate: Path, is_dir: bool) -> bool:
+        ignored = False
+        for pattern in self.patterns:
+            if pattern.dir_only and not is_dir:
+                continue
+            try:
+                relative = candidate.relative_to(pattern.base).as_posix()
+            except ValueError:
+                continue
+            if pattern.regex.fullmatch(relative):
+                ignored = not pattern.negated
+        return ignored
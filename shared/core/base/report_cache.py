@@ -0,0 +1,138 @@
+#!/usr/bin/env python3
+"""
+Report Cache - whole-report reuse for unchanged CI reruns.
+
+PURPOSE: Make a rerun over identical inputs free. A run is keyed on a hash of
+everything that can change its report, and a matching key returns the stored
+report without scanning a single file.
+
+KEY:
+- Scanner package version and the analyzer's external tool version
+- Analyzer configuration
+- Path and contents of every file the scan would analyze
+- Contents of the rule files applied to those files
+- Contents of the false-positive store, which hides findings
+
+Any change produces a new key. Each analyzer keeps one entry, so a miss
+overwrites the previous report instead of growing the cache directory.
+
+FUNCTION SUMMARIES:
+Alongside the report, each Rust function's taint summary (which sources reach
+which sinks) is kept under a hash of the function's own text. Editing one
+function recomputes only its summary; the others are reused as stored.
+"""
+
+import dataclasses
+import hashlib
+import json
+from pathlib import Path
+from typing import Any, Callable, Optional, Union
+
+from analyzers import __version__ as SCANNER_VERSION
+from core.utils.rust_symbols import rust_function_spans
+
+_CACHE_FORMAT = 1
+
+
+def config_digest(config: Any) -> str:
+    """Hash a dataclass config; sets are sorted so ordering never matters."""
+    payload = json.dumps(
+        dataclasses.asdict(config),
+        sort_keys=True,
+        default=lambda value: sorted(value) if isinstance(value, set) else str(value),
+    )
+    return hashlib.sha256(payload.encode("utf-8")).hexdigest()
+
+
+class ReportKey:
+    """Incrementally built cache key for one scan."""
+
+    def __init__(self, analyzer: str, tool_version: str, config: Any):
+        self._hash = hashlib.sha256()
+        for part in (
+            str(_CACHE_FORMAT),
+            SCANNER_VERSION,
+            analyzer,
+            tool_version,
+            config_digest(config),
+        ):
+            self.add_text(part)
+
+    def add_text(self, text: str) -> None:
+        self._hash.update(text.encode("utf-8") + b"\0")
+
+    def add_file(self, label: str, path: Path) -> None:
+        """Mix in a file's label and contents."""
+        self.add_text(label)
+        self._hash.update(path.read_bytes())
+        self._hash.update(b"\0")
+
+    def hexdigest(self) -> str:
+        return self._hash.hexdigest()
+
+
+class ReportCache:
+    """One cached report per analyzer, stored as JSON under cache_dir."""
+
+    def __init__(self, cache_dir: Union[str, Path], analyzer: str):
+        self.path = Path(cache_dir) / f"{analyzer}-report.json"
+        self.functions_path = Path(cache_dir) / f"{analyzer}-functions.json"
+
+    def load(self, key: str) -> Optional[dict[str, Any]]:
+        """Return the cached report dict if it was stored under key."""
+        try:
+            entry = json.loads(self.path.read_text(encoding="utf-8"))
+        except (OSError, json.JSONDecodeError):
+            return None
+        if entry.get("key") != key:
+            return None
+        return entry.get("report")
+
+    def store(self, key: str, report: dict[str, Any]) -> None:
+        self.path.parent.mkdir(parents=True, exist_ok=True)
+        payload = {"format": _CACHE_FORMAT, "key": key, "report": report}
+        self.path.write_text(json.dumps(payload) + "\n", encoding="utf-8")
+
+    def function_summaries(
+        self,
+        file_path: str,
+        source: str,
+        summarize: Callable[[int, int], Any],
+    ) -> tuple[list[tuple[str, Any]], int]:
+        """
+        Return (name, summary) for every function in a Rust source file.
+
+        summarize(first_line, last_line) is called only for functions whose
+        text has no stored summary; the rest reuse theirs. Summaries of
+        functions no longer in the file are dropped.
+
+        Returns
+        -------
+            The summaries in source order, and how many were recomputed
+        """
+        try:
+            entry = json.loads(self.functions_path.read_text(encoding="utf-8"))
+        except (OSError, json.JSONDecodeError):
+            entry = {}
+        files = entry.get("files", {}) if entry.get("format") == _CACHE_FORMAT else {}
+        stored = files.get(file_path, {})
+        lines = source.splitlines()
+        current: dict[str, Any] = {}
+        summaries = []
+        computed = 0
+        for name, first, last in rust_function_spans(source):
+            body = "\n".join(lines[first - 1 : last])
+            digest = hashlib.sha256(body.encode("utf-8")).hexdigest()
+            if digest not in current:
+                if digest in stored:
+                    current[digest] = stored[digest]
+                else:
+                    current[digest] = summarize(first, last)
+                    computed += 1
+            summaries.append((name, current[digest]))
+
+        files[file_path] = current
+        self.functions_path.parent.mkdir(parents=True, exist_ok=True)
+        payload = {"format": _CACHE_FORMAT, "files": files}
+        self.functions_path.write_text(json.dumps(payload) + "\n", encoding="utf-8")
+        return summaries, computed
@@ -0,0 +1,30 @@
+#!/usr/bin/env python3
+"""
+Rule Aliases - legacy rule id mapping.
+
+PURPOSE: Keep suppressions and false-positive fingerprints stable when a rule
+is renamed. When renaming a rule, add `"old-id": "new-id"` here; config and
+suppressions then accept either id, and output always uses the current one.
+"""
+
+from typing import Optional
+
+# Legacy rule id -> current rule id
+RULE_ALIASES: dict[str, str] = {
+    # "unsafe" names Rust unsafe code elsewhere; this rule is about untrusted input
+    "rust-deserialization-unsafe": "rust-untrusted-deserialization",
+}
+
+
+def resolve_alias(rule_id: str) -> Optional[str]:
+    """Return the current id for a legacy rule id, or None if it is not an alias."""
+    return RULE_ALIASES.get(rule_id)
+
+
+def canonical_rule_id(rule_id: str) -> str:
+    """Return the current id for any rule id, following chained renames."""
+    seen = set()
+    while rule_id in RULE_ALIASES and rule_id not in seen:
+        seen.add(rule_id)
+        rule_id = RULE_ALIASES[rule_id]
+    return rule_id
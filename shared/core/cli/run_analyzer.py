@@ -7,74 +7,326 @@ Single entrypoint to run any registered analyzer by registry key.
 Usage examples (ensure PYTHONPATH points to the scripts root):
   python -m core.cli.run_analyzer --analyzer quality:lizard --target . --output-format json
   python -m core.cli.run_analyzer --analyzer security:semgrep --target . --output-format json --min-severity medium
+  cat src/main.rs | python -m core.cli.run_analyzer --analyzer security:semgrep --stdin --path src/main.rs
+  python -m core.cli.run_analyzer --fp-store fp.json --mark-false-positive <fingerprint> --reason "test fixture"
+  python -m core.cli.run_analyzer --fp-store fp.json --report-fp-stats
+  python -m core.cli.run_analyzer --analyzer security:semgrep --target . --fp-store fp.json --generate-suppressions high
+  python -m core.cli.run_analyzer --list-rules --enable-tags hardening
+  python -m core.cli.run_analyzer --explain command-injection
+  python -m core.cli.run_analyzer --analyzer security:semgrep --staged --min-severity high
+  python -m core.cli.run_analyzer --analyzer security:semgrep --target . --report-cache .scan-cache
 """
 
 from __future__ import annotations
 
 import argparse
 import contextlib
+import json
 import sys
+from pathlib import Path
 
 # Python version check
 import core.base.registry_bootstrap  # noqa: F401 - registers analyzers via side effects
+from analyzers.security.rule_catalog import explain_rule, find_rule, list_rules
 from core.base import AnalyzerRegistry, create_analyzer_config
-from core.utils.output_formatter import ResultFormatter
+from core.base.analyzer_base import SOURCE_LANGUAGE_EXTENSIONS
+from core.base.archive_utils import is_archive
+from core.base.false_positives import FalsePositiveStore, generate_suppressions
+from core.utils.output_formatter import SEVERITY_SYMBOLS, LineEnding, ResultFormatter
+from core.utils.progress import ProgressBar
+
+_SARIF_LEVEL_NAMES = ("error", "warning", "note", "none")
 
 
 def _parse_args(argv: list[str] | None = None) -> argparse.Namespace:
     parser = argparse.ArgumentParser(description="Run a registered analyzer by key")
     parser.add_argument(
         "--analyzer",
-        required=True,
         help="Registry key of analyzer to run (e.g., quality:lizard)",
     )
     parser.add_argument(
         "--target",
         default=".",
-        help="Path to analyze (file or directory)",
+        help="Path to analyze (file, directory, or .tar.gz/.tgz/.tar/.zip archive)",
+    )
+    parser.add_argument(
+        "--staged",
+        action="store_true",
+        help="Scan only git-staged lines under --target; exit 1 if any finding remains",
+    )
+    parser.add_argument(
+        "--report-cache",
+        metavar="DIR",
+        help="Reuse the report stored in DIR when no file, rule or config changed",
+    )
+    parser.add_argument(
+        "--stdin",
+        action="store_true",
+        help="Read source from stdin and report findings against --path",
+    )
+    parser.add_argument(
+        "--path",
+        help="Virtual file path for --stdin input (its extension sets the language)",
+    )
+    parser.add_argument(
+        "--language",
+        choices=sorted(SOURCE_LANGUAGE_EXTENSIONS),
+        help="Language of --stdin input when --path has no usable extension",
     )
     parser.add_argument(
         "--output-format",
-        choices=["json", "console"],
+        choices=["json", "console", "html", "markdown", "sarif", "osv"],
         default="json",
         help="Output format",
     )
+    parser.add_argument(
+        "--output-dir",
+        help="Write reports to DIR/report.<ext> instead of printing to stdout",
+    )
+    parser.add_argument(
+        "--formats",
+        nargs="+",
+        choices=["json", "console", "html", "markdown", "sarif", "osv"],
+        default=["json"],
+        help="Report formats to write when --output-dir is set",
+    )
+    parser.add_argument(
+        "--json-chunk-size",
+        type=int,
+        help="With --output-dir, also write chunks/ of N findings per JSON file",
+    )
+    parser.add_argument(
+        "--line-ending",
+        choices=["lf", "crlf"],
+        default="lf",
+        help="Line endings for reports, whether printed or written with --output-dir",
+    )
     parser.add_argument(
         "--min-severity",
         choices=["critical", "high", "medium", "low", "info"],
         default="low",
         help="Minimum severity level to include",
     )
+    parser.add_argument(
+        "--console-min-severity",
+        choices=["critical", "high", "medium", "low", "info"],
+        help="Minimum severity for console output only (default: --min-severity)",
+    )
+    parser.add_argument(
+        "--color",
+        choices=["auto", "always", "never"],
+        default="auto",
+        help="Color console output (auto: only on a terminal without NO_COLOR)",
+    )
+    parser.add_argument(
+        "--severity-symbol",
+        action="append",
+        default=[],
+        metavar="SEVERITY=TEXT",
+        help="Override a console severity prefix, e.g. critical='!!' (repeatable)",
+    )
+    parser.add_argument(
+        "--sarif-level",
+        action="append",
+        default=[],
+        metavar="SEVERITY=LEVEL",
+        help="Override the SARIF level for a severity, e.g. medium=error (repeatable)",
+    )
+    parser.add_argument(
+        "--min-confidence",
+        choices=["high", "medium", "low"],
+        default="low",
+        help="Minimum finding confidence to include",
+    )
+    parser.add_argument(
+        "--enable-tags",
+        nargs="+",
+        default=[],
+        help="Enable opt-in rules carrying any of these tags (e.g., error-handling)",
+    )
+    parser.add_argument(
+        "--suppress-rules",
+        nargs="+",
+        default=[],
+        help="Rule ids to suppress; legacy ids of renamed rules are accepted",
+    )
+    parser.add_argument(
+        "--rule-manifest",
+        action="append",
+        default=[],
+        help="Load declarative rules from a YAML/TOML manifest (repeatable)",
+    )
     parser.add_argument(
         "--max-files",
         type=int,
         help="Maximum number of files to analyze",
     )
+    parser.add_argument(
+        "--threads",
+        type=int,
+        help="Maximum parallel workers for analyzers that support it (default: CPU count)",
+    )
+    parser.add_argument(
+        "--no-redact-secrets",
+        action="store_true",
+        help="Show secret values in finding snippets instead of masking them",
+    )
+    parser.add_argument(
+        "--blame",
+        action="store_true",
+        help="Attach git blame commit, author and date to each finding (slow)",
+    )
+    parser.add_argument(
+        "--fp-store",
+        help="JSON file of findings marked as false positives; matches are hidden",
+    )
+    parser.add_argument(
+        "--mark-false-positive",
+        metavar="FINGERPRINT",
+        help="Record a finding fingerprint in --fp-store and exit",
+    )
+    parser.add_argument(
+        "--reason",
+        default="",
+        help="Why the finding is a false positive (with --mark-false-positive)",
+    )
+    parser.add_argument(
+        "--rule",
+        default="",
+        help="Rule that produced the finding (with --mark-false-positive)",
+    )
+    parser.add_argument(
+        "--report-fp-stats",
+        action="store_true",
+        help="Print false positive counts per rule from --fp-store and exit",
+    )
+    parser.add_argument(
+        "--generate-suppressions",
+        metavar="SEVERITY",
+        choices=["critical", "high", "medium", "low"],
+        help="Scan, then record every finding below SEVERITY in --fp-store and exit",
+    )
+    parser.add_argument(
+        "--list-rules",
+        action="store_true",
+        help="Print bundled and --rule-manifest rules with metadata as JSON, then exit",
+    )
+    parser.add_argument(
+        "--explain",
+        metavar="RULE_ID",
+        help="Print a rule's description, CWE, severity and fix example, then exit",
+    )
+    parser.add_argument(
+        "--dry-run",
+        action="store_true",
+        help="Print the files that would be scanned and their rules as JSON, then exit",
+    )
     parser.add_argument(
         "--summary",
         action="store_true",
         help="Summary mode (limit output to most important findings)",
     )
+    parser.add_argument(
+        "--progress",
+        action="store_true",
+        help="Show files scanned, findings so far and an ETA on stderr (TTY only)",
+    )
     parser.add_argument(
         "--verbose",
         action="store_true",
         help="Enable verbose analyzer logging (if supported)",
     )
-    return parser.parse_args(argv)
+    args = parser.parse_args(argv)
+
+    fp_mode = args.mark_false_positive or args.report_fp_stats
+    if fp_mode and not args.fp_store:
+        parser.error("--fp-store is required to mark or report false positives")
+    if args.generate_suppressions and not args.fp_store:
+        parser.error("--fp-store is required to generate suppressions")
+    catalog_mode = args.list_rules or args.explain
+    if not fp_mode and not catalog_mode and not args.analyzer:
+        parser.error("the following arguments are required: --analyzer")
+    if args.stdin and not args.path:
+        parser.error("--stdin requires --path")
+    if args.json_chunk_size and not args.output_dir:
+        parser.error("--json-chunk-size requires --output-dir")
+    args.severity_symbols = {}
+    for override in args.severity_symbol:
+        severity, sep, symbol = override.partition("=")
+        if not sep or severity not in SEVERITY_SYMBOLS:
+            parser.error(f"--severity-symbol expects SEVERITY=TEXT, got {override!r}")
+        args.severity_symbols[severity] = symbol
+    args.sarif_levels = {}
+    for override in args.sarif_level:
+        severity, sep, level = override.partition("=")
+        if severity not in SEVERITY_SYMBOLS or level not in _SARIF_LEVEL_NAMES:
+            parser.error(f"--sarif-level expects SEVERITY=LEVEL, got {override!r}")
+        args.sarif_levels[severity] = level
+    return args
+
+
+def _print_report(text: str, line_ending: LineEnding) -> None:
+    """Print a rendered report, ending its last line with line_ending too."""
+    output = text + line_ending.value
+    buffer = getattr(sys.stdout, "buffer", None)
+    if buffer is None:
+        sys.stdout.write(output)
+        return
+    # Bytes, so the platform never rewrites the chosen line endings
+    sys.stdout.flush()
+    buffer.write(output.encode("utf-8"))
+
+
+def _run_false_positive_command(args: argparse.Namespace) -> int:
+    store = FalsePositiveStore(args.fp_store)
+    if args.mark_false_positive:
+        store.mark(args.mark_false_positive, args.reason, rule=args.rule)
+        print(f"Marked {args.mark_false_positive} as false positive", file=sys.stderr)
+    if args.report_fp_stats:
+        print(json.dumps(store.stats(), indent=2))
+    return 0
 
 
 def main(argv: list[str] | None = None) -> int:
     args = _parse_args(argv)
 
     try:
+        if args.mark_false_positive or args.report_fp_stats:
+            return _run_false_positive_command(args)
+
         cfg = create_analyzer_config(
             target_path=args.target,
             max_files=args.max_files,
+            threads=args.threads,
             min_severity=args.min_severity,
+            min_confidence=args.min_confidence,
+            enabled_rule_tags=set(args.enable_tags),
+            suppressed_rules=set(args.suppress_rules),
+            rule_manifests=args.rule_manifest,
             summary_mode=args.summary,
             output_format=args.output_format,
+            line_ending=args.line_ending,
+            sarif_level_map=args.sarif_levels,
+            false_positive_store=args.fp_store,
+            redact_secrets=not args.no_redact_secrets,
+            blame=args.blame,
         )
 
+        if args.list_rules:
+            print(json.dumps(list_rules(cfg), indent=2))
+            return 0
+
+        if args.explain:
+            entry = find_rule(args.explain, cfg)
+            if entry is None:
+                message = f"Unknown rule {args.explain!r}. Available rules:"
+                print(message, file=sys.stderr)
+                for rule in list_rules(cfg):
+                    print(f"  {rule['id']}", file=sys.stderr)
+                return 2
+            print(explain_rule(entry))
+            return 0
+
         analyzer = AnalyzerRegistry.create(args.analyzer, config=cfg)
 
         # Some analyzers may honor a verbose attribute
@@ -82,18 +334,122 @@ def main(argv: list[str] | None = None) -> int:
             with contextlib.suppress(Exception):
                 analyzer.verbose = bool(args.verbose)
 
-        result = analyzer.analyze(args.target)
+        if args.dry_run:
+            print(json.dumps(analyzer.plan([args.target]).to_dict(), indent=2))
+            return 0
 
-        if args.output_format == "console":
-            print(ResultFormatter.format_console_output(result))
-        else:
+        # Draws on stderr only when it is a terminal
+        progress = ProgressBar(enabled=None if args.progress else False)
+        analyzer.on_scan_event = progress
+        with progress:
+            if args.staged:
+                result = analyzer.scan_staged(args.target)
+            elif args.stdin:
+                result = analyzer.analyze_source(
+                    sys.stdin.read(), args.path, language=args.language
+                )
+            elif is_archive(args.target):
+                result = analyzer.analyze_archive(args.target)
+            elif args.report_cache:
+                result = analyzer.scan_cached([args.target], args.report_cache)
+            else:
+                result = analyzer.analyze(args.target)
+
+        if args.generate_suppressions:
+            before = len(FalsePositiveStore(args.fp_store).entries)
+            store = generate_suppressions(
+                result, args.generate_suppressions, args.fp_store
+            )
+            added = len(store.entries) - before
             print(
-                result.to_json(
-                    indent=2, summary_mode=args.summary, min_severity=args.min_severity
+                f"Suppressed {added} findings below {args.generate_suppressions} "
+                f"in {args.fp_store}",
+                file=sys.stderr,
+            )
+            return 0 if result.success else 1
+
+        console_min_severity = (
+            {"console": args.console_min_severity}
+            if args.console_min_severity
+            else None
+        )
+        line_ending = LineEnding[cfg.line_ending.upper()]
+        if args.output_dir:
+            for path in result.write_all(
+                args.output_dir,
+                args.formats,
+                summary_mode=args.summary,
+                min_severity=args.min_severity,
+                line_ending=line_ending,
+                format_min_severity=console_min_severity,
+                sarif_level_map=cfg.sarif_level_map,
+            ):
+                print(f"Wrote {path}", file=sys.stderr)
+            if args.json_chunk_size:
+                manifest = result.write_json_chunks(
+                    str(Path(args.output_dir) / "chunks"),
+                    args.json_chunk_size,
+                    min_severity=args.min_severity,
+                    line_ending=line_ending,
                 )
+                print(f"Wrote {manifest}", file=sys.stderr)
+        elif args.output_format == "console":
+            _print_report(
+                ResultFormatter.format_console_output(
+                    result,
+                    line_ending,
+                    min_severity=args.console_min_severity or args.min_severity,
+                    symbols=args.severity_symbols,
+                    color={"auto": None, "always": True, "never": False}[args.color],
+                ),
+                line_ending,
+            )
+        elif args.output_format == "sarif":
+            _print_report(
+                result.to_sarif(
+                    min_severity=args.min_severity,
+                    level_map=cfg.sarif_level_map,
+                    line_ending=line_ending,
+                ),
+                line_ending,
+            )
+        elif args.output_format == "osv":
+            _print_report(
+                result.to_osv(min_severity=args.min_severity, line_ending=line_ending),
+                line_ending,
+            )
+        elif args.output_format == "markdown":
+            _print_report(
+                result.to_markdown(
+                    summary_mode=args.summary,
+                    min_severity=args.min_severity,
+                    line_ending=line_ending,
+                ),
+                line_ending,
+            )
+        elif args.output_format == "html":
+            _print_report(
+                result.to_html(
+                    summary_mode=args.summary,
+                    min_severity=args.min_severity,
+                    line_ending=line_ending,
+                ),
+                line_ending,
+            )
+        else:
+            _print_report(
+                result.to_json(
+                    indent=2,
+                    summary_mode=args.summary,
+                    min_severity=args.min_severity,
+                    line_ending=line_ending,
+                ),
+                line_ending,
             )
         sys.stdout.flush()
 
+        if args.staged and result.findings_at_or_above(args.min_severity):
+            return 1
         return 0 if result.success else 1
 
     except KeyError as e:
@@ -0,0 +1,70 @@
+#!/usr/bin/env python3
+"""
+Git blame enrichment for findings.
+
+Attaches the commit that last touched each finding's line, plus its author
+and date, so findings can be routed to owners during triage. Blame runs once
+per file and is cached; files outside a git work tree are skipped silently.
+"""
+
+import subprocess
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Optional
+
+
+class BlameCache:
+    """Per-file cache of `git blame --line-porcelain` results."""
+
+    def __init__(self, timeout_seconds: int = 30):
+        self.timeout_seconds = timeout_seconds
+        self._files: dict[Path, dict[int, dict[str, str]]] = {}
+
+    def blame_line(self, file_path: Path, line_number: int) -> Optional[dict[str, str]]:
+        """Return introduced_commit/author/date for a line, or None if unknown."""
+        resolved = file_path.resolve()
+        if resolved not in self._files:
+            self._files[resolved] = self._blame_file(resolved)
+        return self._files[resolved].get(line_number)
+
+    def _blame_file(self, file_path: Path) -> dict[int, dict[str, str]]:
+        if not file_path.is_file():
+            return {}
+        try:
+            result = subprocess.run(
+                ["git", "blame", "--line-porcelain", "--", file_path.name],
+                cwd=file_path.parent,
+                capture_output=True,
+                text=True,
+                timeout=self.timeout_seconds,
+            )
+        except (OSError, subprocess.TimeoutExpired):
+            return {}
+        if result.returncode != 0:
+            return {}
+        return _parse_line_porcelain(result.stdout)
+
+
+def _parse_line_porcelain(output: str) -> dict[int, dict[str, str]]:
+    """Map final line numbers to blame info from `--line-porcelain` output."""
+    lines: dict[int, dict[str, str]] = {}
+    current: dict[str, str] = {}
+    line_number = 0
+
+    for row in output.splitlines():
+        if row.startswith("\t"):
+            lines[line_number] = current
+            current = {}
+            continue
+        key, _, value = row.partition(" ")
+        if len(key) == 40 and not current:
+            parts = value.split()
+            current = {"introduced_commit": key}
+            line_number = int(parts[1]) if len(parts) > 1 else 0
+        elif key == "author":
+            current["author"] = value
+        elif key == "author-time" and value.isdigit():
+            timestamp = datetime.fromtimestamp(int(value), tz=timezone.utc)
+            current["date"] = timestamp.isoformat()
+
+    return lines
@@ -0,0 +1,105 @@
+#!/usr/bin/env python3
+"""
+Staged-change lookup for pre-commit scans.
+
+Lists the files staged in a git repository together with the line numbers
+each staged hunk adds or changes, so a scan can report only what the commit
+introduces. Deleted files are left out; renamed and copied files count as
+their new path. Paths are relative to the directory git runs in, which may
+be a subdirectory of the work tree.
+"""
+
+import re
+import subprocess
+from pathlib import Path
+from typing import Optional, Union
+
+_HUNK_HEADER = re.compile(r"^@@ -\d+(?:,\d+)? \+(\d+)(?:,(\d+))? @@")
+
+# Escapes git uses in C-style quoted paths, besides \ooo octal bytes
+_QUOTED_ESCAPES = {"a": 7, "b": 8, "t": 9, "n": 10, "v": 11, "f": 12, "r": 13}
+
+
+def _git(repo_root: Union[str, Path], *args: str) -> str:
+    try:
+        result = subprocess.run(
+            ["git", *args],
+            cwd=repo_root,
+            capture_output=True,
+            text=True,
+            timeout=60,
+        )
+    except (OSError, subprocess.TimeoutExpired) as e:
+        raise RuntimeError(f"git {args[0]} failed: {e}") from e
+    if result.returncode != 0:
+        raise RuntimeError(f"git {args[0]} failed: {result.stderr.strip()}")
+    return result.stdout
+
+
+def _unquote(body: str) -> str:
+    """Decode the inside of a C-style quoted git path."""
+    raw = bytearray()
+    i = 0
+    while i < len(body):
+        octal = body[i + 1 : i + 4]
+        if body[i] != "\\" or i + 1 == len(body):
+            raw.extend(body[i].encode("utf-8"))
+            i += 1
+        elif len(octal) == 3 and all(digit in "01234567" for digit in octal):
+            raw.append(int(octal, 8))
+            i += 4
+        else:
+            raw.append(_QUOTED_ESCAPES.get(body[i + 1], ord(body[i + 1])))
+            i += 2
+    return raw.decode("utf-8", errors="surrogateescape")
+
+
+def _diff_header_path(target: str) -> Optional[str]:
+    """
+    Return the path in a `+++` header, or None for /dev/null.
+
+    git quotes paths with special or non-ASCII characters, writing bytes as
+    octal escapes, and ends unquoted paths that contain a space with a tab.
+    """
+    if len(target) > 1 and target.startswith('"') and target.endswith('"'):
+        target = _unquote(target[1:-1])
+    else:
+        target = target.rstrip("\t")
+    return target[2:] if target.startswith("b/") else None
+
+
+def staged_line_ranges(repo_root: Union[str, Path]) -> dict[str, set[int]]:
+    """
+    Map each staged file under repo_root (posix path relative to it) to its lines.
+
+    repo_root may be a subdirectory of the work tree; files staged elsewhere
+    in the repository are left out.
+
+    Raises
+    ------
+        RuntimeError: If git is unavailable or repo_root is not a work tree
+    """
+    diff_args = ["diff", "--cached", "--relative", "--diff-filter=ACMR"]
+    names = _git(repo_root, *diff_args, "--name-only", "-z")
+    staged: dict[str, set[int]] = {name: set() for name in names.split("\0") if name}
+
+    current = None
+    diff = _git(
+        repo_root,
+        *diff_args,
+        "--unified=0",
+        "--no-color",
+        "--src-prefix=a/",
+        "--dst-prefix=b/",
+    )
+    for line in diff.splitlines():
+        if line.startswith("+++ "):
+            current = _diff_header_path(line[4:])
+            continue
+        header = _HUNK_HEADER.match(line)
+        if header and current in staged:
+            start = int(header.group(1))
+            count = int(header.group(2)) if header.group(2) is not None else 1
+            staged[current].update(range(start, start + count))
+
+    return staged
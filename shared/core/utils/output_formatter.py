@@ -5,11 +5,16 @@ Output formatting utilities for AI-Assisted Workflows scriptable workflows.
 Provides standardized JSON output format for all analysis scripts.
 """
 
+import html
 import json
+import os
+import re
+import sys
 import time
 from dataclasses import dataclass
-from datetime import datetime
+from datetime import datetime, timezone
 from enum import Enum
+from pathlib import Path
 from typing import Any, Optional
 
 
@@ -23,6 +28,125 @@ class Severity(Enum):
     INFO = "info"
 
 
+class LineEnding(Enum):
+    """Line endings for text report output."""
+
+    LF = "\n"
+    CRLF = "\r\n"
+
+
+def _with_line_ending(text: str, line_ending: LineEnding) -> str:
+    """Normalize all newlines in rendered text to the requested line ending."""
+    normalized = text.replace("\r\n", "\n")
+    if line_ending is LineEnding.LF:
+        return normalized
+    return normalized.replace("\n", line_ending.value)
+
+
+# Console prefixes, so severity never depends on color alone
+SEVERITY_SYMBOLS = {
+    "critical": "[CRIT]",
+    "high": "[HIGH]",
+    "medium": "[MED]",
+    "low": "[LOW]",
+    "info": "[INFO]",
+}
+
+_SEVERITY_COLORS = {
+    "critical": "\033[1;31m",
+    "high": "\033[31m",
+    "medium": "\033[33m",
+    "low": "\033[36m",
+    "info": "\033[2m",
+}
+_RESET_COLOR = "\033[0m"
+
+
+def console_color_enabled(stream: Any = None) -> bool:
+    """Color only when writing to a terminal and NO_COLOR is unset (no-color.org)."""
+    stream = stream or sys.stdout
+    if os.environ.get("NO_COLOR"):
+        return False
+    return bool(getattr(stream, "isatty", lambda: False)())
+
+
+_CARGO_PACKAGE_NAME = re.compile(
+    r'^\[package\][^\[]*?^\s*name\s*=\s*["\']([^"\']+)["\']', re.MULTILINE | re.DOTALL
+)
+
+
+def _find_cargo_package(
+    file_path: Path, cache: dict[Path, Optional[tuple[str, Path]]]
+) -> Optional[tuple[str, Path]]:
+    """
+    Return (package name, manifest path) of the crate containing file_path.
+
+    Walks up to the nearest Cargo.toml with a [package] section, so files in a
+    workspace member resolve to the member rather than the workspace root.
+    """
+    for directory in file_path.parents:
+        if directory in cache:
+            return cache[directory]
+        manifest = directory / "Cargo.toml"
+        if manifest.is_file():
+            try:
+                content = manifest.read_text(encoding="utf-8", errors="ignore")
+            except OSError:
+                content = ""
+            match = _CARGO_PACKAGE_NAME.search(content)
+            if match:
+                cache[directory] = (match.group(1), manifest)
+                return cache[directory]
+    return None
+
+
+# SARIF has three result levels; critical/high fail a run, medium warns
+_SARIF_LEVELS = {
+    "critical": "error",
+    "high": "error",
+    "medium": "warning",
+    "low": "note",
+    "info": "note",
+}
+
+
+# OSV's "Ubuntu" severity type uses the same words, bar info -> negligible
+_OSV_SEVERITIES = {
+    "critical": "critical",
+    "high": "high",
+    "medium": "medium",
+    "low": "low",
+    "info": "negligible",
+}
+
+
+def _sarif_location(
+    file_path: Optional[str], line_number: Optional[int], column: Optional[int] = None
+) -> dict[str, Any]:
+    """Build a SARIF physicalLocation for a file/line (and optional column)."""
+    region: dict[str, Any] = {"startLine": max(line_number or 1, 1)}
+    if column:
+        region["startColumn"] = column
+    return {
+        "physicalLocation": {
+            "artifactLocation": {"uri": (file_path or "").replace("\\", "/")},
+            "region": region,
+        }
+    }
+
+
+def _sarif_code_flow(flow: list[dict[str, Any]]) -> dict[str, Any]:
+    """Build a SARIF codeFlow from source/propagation/sink steps."""
+    locations = []
+    for step in flow:
+        location = _sarif_location(
+            step["file_path"], step["line_number"], step.get("column")
+        )
+        location["message"] = {"text": step["content"]}
+        locations.append({"location": location, "kinds": [step["kind"]]})
+    return {"threadFlows": [{"locations": locations}]}
+
+
 class AnalysisType(Enum):
     """Types of analysis."""
 
@@ -33,6 +157,24 @@ class AnalysisType(Enum):
     MONITORING = "monitoring"
 
 
+# Rank for merge precedence; lower is more severe / more confident
+_SEVERITY_RANK = {"critical": 0, "high": 1, "medium": 2, "low": 3, "info": 4}
+_CONFIDENCE_RANK = {"high": 0, "medium": 1, "low": 2}
+
+
+@dataclass(frozen=True)
+class FindingKey:
+    """
+    Identity of a finding across scans: rule id plus fingerprint.
+
+    The fingerprint (see core.base.false_positives) ignores line numbers, so
+    the same issue keeps its key when unrelated edits move it.
+    """
+
+    rule_id: str
+    fingerprint: str
+
+
 class Finding:
     """Represents a single analysis finding."""
 
@@ -56,6 +198,61 @@ class Finding:
         self.recommendation = recommendation
         self.evidence = evidence or {}
 
+    @property
+    def rule_id(self) -> str:
+        return self.evidence.get("check_id") or self.title
+
+    @property
+    def key(self) -> FindingKey:
+        return FindingKey(
+            self.rule_id, self.evidence.get("fingerprint") or self.finding_id
+        )
+
+    def merge(self, other: "Finding") -> "Finding":
+        """
+        Combine two findings that report the same issue into a new finding.
+
+        Precedence:
+        - Location, title, description and recommendation come from self, the
+          primary finding; callers pass the finding they want reported first
+        - Severity is the higher of the two, and so is evidence["confidence"]
+          when either side records one
+        - evidence["also_matched"] lists, sorted, every rule id from either
+          side (including earlier merges) other than self's rule id
+        - Other evidence keys come from self, with other's filling the gaps
+        """
+        evidence = {**other.evidence, **self.evidence}
+        severity = min(
+            self.severity, other.severity, key=lambda s: _SEVERITY_RANK[s.value]
+        )
+        confidences = [
+            str(finding.evidence["confidence"]).lower()
+            for finding in (self, other)
+            if finding.evidence.get("confidence")
+        ]
+        if confidences:
+            evidence["confidence"] = min(
+                confidences, key=lambda c: _CONFIDENCE_RANK.get(c, 3)
+            )
+        also_matched = {
+            other.rule_id,
+            *self.evidence.get("also_matched", []),
+            *other.evidence.get("also_matched", []),
+        } - {self.rule_id}
+        if also_matched:
+            evidence["also_matched"] = sorted(also_matched)
+
+        return Finding(
+            finding_id=self.finding_id,
+            title=self.title,
+            description=self.description,
+            severity=severity,
+            file_path=self.file_path,
+            line_number=self.line_number,
+            recommendation=self.recommendation,
+            evidence=evidence,
+        )
+
     def to_dict(self) -> dict[str, Any]:
         """Convert finding to dictionary."""
         return {
@@ -91,6 +288,37 @@ class AnalysisResult:
         self.success = True
         self.error_message = None
 
+    @classmethod
+    def from_dict(cls, data: dict[str, Any]) -> "AnalysisResult":
+        """Rebuild a result from ``to_dict(min_severity="info")`` output."""
+        result = cls(
+            AnalysisType(data["analysis_type"]),
+            data["script_name"],
+            data["target_path"],
+            findings=[
+                Finding(
+                    finding_id=item["id"],
+                    title=item["title"],
+                    description=item["description"],
+                    severity=Severity(item["severity"]),
+                    file_path=item.get("file_path"),
+                    line_number=item.get("line_number"),
+                    recommendation=item.get("recommendation"),
+                    evidence=item.get("evidence"),
+                )
+                for item in [
+                    *data.get("findings", []),
+                    *data.get("test_only_findings", []),
+                ]
+            ],
+            metadata=data.get("metadata"),
+        )
+        result.timestamp = data.get("timestamp", result.timestamp)
+        result.execution_time = data.get("execution_time", 0.0)
+        result.success = data.get("success", True)
+        result.error_message = data.get("error_message")
+        return result
+
     def add_finding(self, finding: Finding):
         """Add a finding to the result."""
         self.findings.append(finding)
@@ -111,26 +339,124 @@ class AnalysisResult:
             summary[finding.severity.value] += 1
         return summary
 
-    def to_dict(
-        self, summary_mode: bool = False, min_severity: str = "low"
-    ) -> dict[str, Any]:
+    def secret_groups(self) -> list[dict[str, Any]]:
         """
-        Convert result to dictionary.
+        Group secret findings by unique secret value.
 
-        Args:
-            summary_mode: If True, limit findings to top 10 critical/high severity
-            min_severity: Minimum severity level to include (critical|high|medium|low)
+        A key pasted in ten places becomes one group with ten occurrences.
+        Groups are keyed by the hash recorded in ``evidence["hashed_secret"]``;
+        the secret itself is never included.
         """
-        # Filter by minimum severity
+        groups: dict[str, dict[str, Any]] = {}
+        for finding in self.findings:
+            secret_hash = finding.evidence.get("hashed_secret")
+            if not secret_hash:
+                continue
+            group = groups.setdefault(
+                secret_hash,
+                {
+                    "secret_hash": secret_hash,
+                    "secret_types": [],
+                    "severity": finding.severity.value,
+                    "occurrences": [],
+                },
+            )
+            secret_type = finding.evidence.get("secret_type")
+            if secret_type and secret_type not in group["secret_types"]:
+                group["secret_types"].append(secret_type)
+            group["occurrences"].append(
+                {"file_path": finding.file_path, "line_number": finding.line_number}
+            )
+
+        return sorted(
+            groups.values(), key=lambda g: (-len(g["occurrences"]), g["secret_hash"])
+        )
+
+    def by_crate(self) -> list[dict[str, Any]]:
+        """
+        Group findings by the Rust crate that owns each file.
+
+        The crate is the nearest ancestor Cargo.toml with a [package] name, so
+        monorepo findings can be routed to crate owners. Relative finding paths
+        resolve against target_path; files outside any crate group under None.
+        """
+        cache: dict[Path, Optional[tuple[str, Path]]] = {}
+        groups: dict[Optional[str], dict[str, Any]] = {}
+        for finding in self.findings:
+            package = None
+            if finding.file_path:
+                path = Path(finding.file_path)
+                if not path.is_absolute():
+                    path = Path(self.target_path) / path
+                package = _find_cargo_package(path.resolve(), cache)
+
+            name, manifest = package or (None, None)
+            group = groups.setdefault(
+                name,
+                {
+                    "crate": name,
+                    "manifest": str(manifest) if manifest else None,
+                    "summary": {severity.value: 0 for severity in Severity},
+                    "findings": [],
+                },
+            )
+            group["summary"][finding.severity.value] += 1
+            group["findings"].append(finding.to_dict())
+
+        return sorted(
+            groups.values(), key=lambda g: (g["crate"] is None, g["crate"] or "")
+        )
+
+    def findings_at_or_above(self, min_severity: str) -> list[Finding]:
+        """Return findings at or above min_severity (unknown values mean low)."""
         severity_order = {"critical": 0, "high": 1, "medium": 2, "low": 3, "info": 4}
 
         min_severity_level = severity_order.get(min_severity, 3)
-        filtered_findings = [
+        return [
             f
             for f in self.findings
             if severity_order.get(f.severity.value, 4) <= min_severity_level
         ]
 
+    def findings_in_range(
+        self, path: str, start: tuple[int, int], end: tuple[int, int]
+    ) -> list[Finding]:
+        """
+        Return findings in path whose span intersects the range start..end.
+
+        Positions are 1-based ``(line, column)`` pairs as Semgrep reports them;
+        LSP positions are 0-based, so callers add one to both parts. Nothing is
+        rescanned, so this is cheap on a ``scan_cached`` result when serving
+        ``textDocument/diagnostic`` requests. A finding without a recorded
+        column or end covers its whole start line.
+        """
+        target = os.path.normpath(path)
+        matches = []
+        for finding in self.findings:
+            if os.path.normpath(finding.file_path or "") != target:
+                continue
+            line = finding.line_number or 0
+            span_start = (line, finding.evidence.get("column") or 0)
+            span_end = (
+                finding.evidence.get("end_line") or line,
+                finding.evidence.get("end_column") or sys.maxsize,
+            )
+            if span_start <= end and start <= span_end:
+                matches.append(finding)
+        return matches
+
+    def to_dict(
+        self, summary_mode: bool = False, min_severity: str = "low"
+    ) -> dict[str, Any]:
+        """
+        Convert result to dictionary.
+
+        Args:
+            summary_mode: If True, limit findings to top 10 critical/high severity
+            min_severity: Minimum severity level to include (critical|high|medium|low)
+        """
+        filtered_findings = self.findings_at_or_above(min_severity)
+
         findings_to_include = filtered_findings
 
         if summary_mode and len(filtered_findings) > 10:
@@ -157,9 +483,17 @@ class AnalysisResult:
             "success": self.success,
             "error_message": self.error_message,
             "summary": self.get_summary(),
-            "findings": [finding.to_dict() for finding in findings_to_include],
+            "findings": [
+                finding.to_dict()
+                for finding in findings_to_include
+                if not finding.evidence.get("test_only")
+            ],
             "metadata": self.metadata,
         }
+        # Secrets in test code are listed apart so they don't drown real ones
+        test_only = [f for f in findings_to_include if f.evidence.get("test_only")]
+        if test_only:
+            result["test_only_findings"] = [finding.to_dict() for finding in test_only]
 
         # Add filtering/truncation info
         if min_severity != "low":
@@ -177,14 +511,488 @@ class AnalysisResult:
         return result
 
     def to_json(
-        self, indent: int = 2, summary_mode: bool = False, min_severity: str = "low"
+        self,
+        indent: int = 2,
+        summary_mode: bool = False,
+        min_severity: str = "low",
+        line_ending: LineEnding = LineEnding.LF,
     ) -> str:
         """Convert result to JSON string."""
-        return json.dumps(
+        rendered = json.dumps(
             self.to_dict(summary_mode=summary_mode, min_severity=min_severity),
             indent=indent,
             ensure_ascii=False,
         )
+        return _with_line_ending(rendered, line_ending)
+
+    def write_json_chunks(
+        self,
+        output_dir: str,
+        per_file: int,
+        min_severity: str = "low",
+        line_ending: LineEnding = LineEnding.LF,
+    ) -> Path:
+        """
+        Write findings as numbered JSON pages plus a manifest.
+
+        Each ``findings-NNNN.json`` holds up to ``per_file`` findings in report
+        order; ``manifest.json`` lists the pages and totals so consumers can
+        process pages independently. Findings are serialized unchanged, so
+        fingerprints match the single-file report.
+
+        Returns
+        -------
+            Path of the written manifest
+        """
+        if per_file <= 0:
+            raise ValueError("per_file must be positive")
+
+        findings = [f.to_dict() for f in self.findings_at_or_above(min_severity)]
+        pages = [
+            findings[start : start + per_file]
+            for start in range(0, len(findings), per_file)
+        ] or [[]]
+
+        directory = Path(output_dir)
+        chunks = []
+        try:
+            directory.mkdir(parents=True, exist_ok=True)
+            for number, page in enumerate(pages, 1):
+                name = f"findings-{number:04d}.json"
+                payload = {
+                    "chunk": number,
+                    "total_chunks": len(pages),
+                    "findings": page,
+                }
+                rendered = json.dumps(payload, indent=2, ensure_ascii=False)
+                (directory / name).write_bytes(
+                    _with_line_ending(rendered, line_ending).encode("utf-8")
+                )
+                chunks.append({"file": name, "findings": len(page)})
+
+            manifest = {
+                "analysis_type": self.analysis_type.value,
+                "script_name": self.script_name,
+                "target_path": self.target_path,
+                "timestamp": self.timestamp,
+                "success": self.success,
+                "summary": self.get_summary(),
+                "total_findings": len(findings),
+                "per_file": per_file,
+                "chunks": chunks,
+            }
+            manifest_path = directory / "manifest.json"
+            rendered = json.dumps(manifest, indent=2, ensure_ascii=False)
+            manifest_path.write_bytes(
+                _with_line_ending(rendered, line_ending).encode("utf-8")
+            )
+        except OSError as e:
+            raise OSError(f"Failed to write report chunks to {directory}: {e}") from e
+
+        return manifest_path
+
+    def to_sarif(
+        self,
+        summary_mode: bool = False,
+        min_severity: str = "low",
+        line_ending: LineEnding = LineEnding.LF,
+        level_map: Optional[dict[str, str]] = None,
+    ) -> str:
+        """
+        Render result as a SARIF 2.1.0 log.
+
+        Findings carrying a taint flow in ``evidence["flow"]`` get a ``codeFlows``
+        entry, so IDEs can step from source to sink. ``level_map`` overrides the
+        SARIF ``level`` per severity, e.g. ``{"medium": "error"}`` so medium
+        findings fail GitHub code scanning checks.
+        """
+        levels = {**_SARIF_LEVELS, **(level_map or {})}
+        report = self.to_dict(summary_mode=summary_mode, min_severity=min_severity)
+        rule_ids: list[str] = []
+        results = []
+        for finding in report["findings"] + report.get("test_only_findings", []):
+            rule_id = finding["evidence"].get("check_id") or finding["title"]
+            if rule_id not in rule_ids:
+                rule_ids.append(rule_id)
+
+            sarif_result: dict[str, Any] = {
+                "ruleId": rule_id,
+                "level": levels[finding["severity"]],
+                "message": {"text": finding["description"]},
+                "locations": [
+                    _sarif_location(finding["file_path"], finding["line_number"])
+                ],
+            }
+            flow = finding["evidence"].get("flow")
+            if flow:
+                sarif_result["codeFlows"] = [_sarif_code_flow(flow)]
+            if finding["evidence"].get("test_only"):
+                sarif_result["properties"] = {"tags": ["test-only"]}
+            results.append(sarif_result)
+
+        sarif = {
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [
+                {
+                    "tool": {
+                        "driver": {
+                            "name": self.script_name,
+                            "rules": [{"id": rule_id} for rule_id in rule_ids],
+                        }
+                    },
+                    "results": results,
+                }
+            ],
+        }
+        return _with_line_ending(
+            json.dumps(sarif, indent=2, ensure_ascii=False), line_ending
+        )
+
+    def to_osv(
+        self,
+        summary_mode: bool = False,
+        min_severity: str = "low",
+        line_ending: LineEnding = LineEnding.LF,
+    ) -> str:
+        """
+        Render findings as a JSON array of OSV-style records.
+
+        OSV describes vulnerable package versions, not source locations, so the
+        mapping is approximate and meant for internal aggregators only:
+
+        - ``id`` is ``SCAN-<fingerprint>`` (falling back to the finding id), so
+          it is stable across runs but not a registered OSV identifier
+        - ``severity`` uses the ``Ubuntu`` type, the only OSV type with word
+          scores; ``info`` becomes ``negligible``
+        - ``affected[].package.name`` is the file path and the range events are
+          1-based line numbers rather than versions
+        - rule id, confidence and recommendation go in ``database_specific``
+        """
+        report = self.to_dict(summary_mode=summary_mode, min_severity=min_severity)
+        modified = (
+            datetime.fromisoformat(self.timestamp)
+            .astimezone(timezone.utc)
+            .strftime("%Y-%m-%dT%H:%M:%SZ")
+        )
+        records = []
+        for finding in report["findings"] + report.get("test_only_findings", []):
+            evidence = finding["evidence"]
+            line = str(max(finding["line_number"] or 1, 1))
+            path = (finding["file_path"] or "").replace("\\", "/")
+            records.append(
+                {
+                    "schema_version": "1.6.0",
+                    "id": f"SCAN-{evidence.get('fingerprint') or finding['id']}",
+                    "modified": modified,
+                    "summary": finding["title"],
+                    "details": finding["description"],
+                    "severity": [
+                        {
+                            "type": "Ubuntu",
+                            "score": _OSV_SEVERITIES[finding["severity"]],
+                        }
+                    ],
+                    "affected": [
+                        {
+                            "package": {"ecosystem": "Source", "name": path},
+                            "ranges": [
+                                {
+                                    "type": "ECOSYSTEM",
+                                    "events": [
+                                        {"introduced": line},
+                                        {"last_affected": line},
+                                    ],
+                                }
+                            ],
+                        }
+                    ],
+                    "database_specific": {
+                        "rule": evidence.get("check_id") or finding["title"],
+                        "confidence": evidence.get("confidence"),
+                        "recommendation": finding["recommendation"],
+                        "source": self.script_name,
+                    },
+                }
+            )
+        return _with_line_ending(
+            json.dumps(records, indent=2, ensure_ascii=False), line_ending
+        )
+
+    def to_html(
+        self,
+        summary_mode: bool = False,
+        min_severity: str = "low",
+        line_ending: LineEnding = LineEnding.LF,
+    ) -> str:
+        """
+        Render result as a self-contained HTML page.
+
+        The report JSON is embedded in the page and rendered client-side, so
+        severity filtering is instant and the file works fully offline.
+        """
+        report_json = json.dumps(
+            self.to_dict(summary_mode=summary_mode, min_severity=min_severity),
+            ensure_ascii=False,
+        ).replace("</", "<\\/")
+        title = html.escape(
+            f"{self.analysis_type.value.replace('_', ' ').title()} Report: "
+            f"{self.target_path}"
+        )
+        page = _HTML_TEMPLATE.replace("__TITLE__", title).replace(
+            "__REPORT_JSON__", report_json
+        )
+        return _with_line_ending(page, line_ending)
+
+    def to_markdown(
+        self,
+        summary_mode: bool = False,
+        min_severity: str = "low",
+        line_ending: LineEnding = LineEnding.LF,
+    ) -> str:
+        """
+        Render result as a Markdown report for PR comments and wikis.
+
+        Findings are listed in one table per section, with test-only findings
+        apart as in the other renderers. Pipes and newlines in cell text are
+        escaped so a finding never breaks the table.
+        """
+        report = self.to_dict(summary_mode=summary_mode, min_severity=min_severity)
+
+        def cell(value: Any) -> str:
+            return " ".join(str(value).splitlines()).replace("|", "\\|")
+
+        def table(findings: list[dict[str, Any]]) -> list[str]:
+            rows = [
+                "| Severity | Location | Title | Description |",
+                "| --- | --- | --- | --- |",
+            ]
+            for finding in findings:
+                location = f"{finding['file_path']}:{finding['line_number']}"
+                rows.append(
+                    f"| {finding['severity']} | `{cell(location)}` "
+                    f"| {cell(finding['title'])} | {cell(finding['description'])} |"
+                )
+            return rows
+
+        title = self.analysis_type.value.replace("_", " ").title()
+        lines = [
+            f"# {title} Report: {self.target_path}",
+            "",
+            f"- Script: `{self.script_name}`",
+            f"- Executed: {self.timestamp}",
+            f"- Duration: {self.execution_time:.3f}s",
+            "",
+        ]
+        if not self.success:
+            lines.extend([f"**Failed:** {self.error_message}", ""])
+            return _with_line_ending("\n".join(lines), line_ending)
+
+        lines.extend(["## Findings", ""])
+        if report["findings"]:
+            lines.extend(table(report["findings"]))
+        else:
+            lines.append("No findings.")
+        test_only = report.get("test_only_findings", [])
+        if test_only:
+            lines.extend(["", f"## Test-only findings ({len(test_only)})", ""])
+            lines.extend(table(test_only))
+        lines.append("")
+        return _with_line_ending("\n".join(lines), line_ending)
+
+    def write_all(
+        self,
+        output_dir: str,
+        formats: list[str],
+        summary_mode: bool = False,
+        min_severity: str = "low",
+        line_ending: LineEnding = LineEnding.LF,
+        format_min_severity: Optional[dict[str, str]] = None,
+        sarif_level_map: Optional[dict[str, str]] = None,
+    ) -> list[Path]:
+        """
+        Write the result in several formats into one directory.
+
+        Each format is written to ``report.<ext>`` as UTF-8 without a BOM; the
+        directory is created if missing. Unknown formats are rejected before
+        anything is written. ``format_min_severity`` overrides ``min_severity``
+        per format, so one scan can feed a full archive and a concise summary.
+        ``sarif_level_map`` is passed through to ``to_sarif``.
+
+        Returns
+        -------
+            Paths of the written report files, in the order requested
+        """
+        overrides = format_min_severity or {}
+
+        def severity_for(fmt: str) -> str:
+            return overrides.get(fmt, min_severity)
+
+        options = {"summary_mode": summary_mode, "line_ending": line_ending}
+        renderers = {
+            "json": (
+                "json",
+                lambda: self.to_json(min_severity=severity_for("json"), **options),
+            ),
+            "html": (
+                "html",
+                lambda: self.to_html(min_severity=severity_for("html"), **options),
+            ),
+            "sarif": (
+                "sarif",
+                lambda: self.to_sarif(
+                    min_severity=severity_for("sarif"),
+                    level_map=sarif_level_map,
+                    **options,
+                ),
+            ),
+            "markdown": (
+                "md",
+                lambda: self.to_markdown(
+                    min_severity=severity_for("markdown"), **options
+                ),
+            ),
+            "osv": (
+                "osv.json",
+                lambda: self.to_osv(min_severity=severity_for("osv"), **options),
+            ),
+            "console": (
+                "txt",
+                lambda: ResultFormatter.format_console_output(
+                    self, line_ending, min_severity=severity_for("console")
+                ),
+            ),
+        }
+        unknown = [fmt for fmt in formats if fmt not in renderers]
+        if unknown:
+            raise ValueError(
+                f"Unsupported report formats {unknown}; expected {sorted(renderers)}"
+            )
+
+        directory = Path(output_dir)
+        written = []
+        try:
+            directory.mkdir(parents=True, exist_ok=True)
+            for fmt in dict.fromkeys(formats):
+                extension, render = renderers[fmt]
+                path = directory / f"report.{extension}"
+                # Bytes, so the platform never rewrites the chosen line endings
+                path.write_bytes(render().encode("utf-8"))
+                written.append(path)
+        except OSError as e:
+            raise OSError(f"Failed to write reports to {directory}: {e}") from e
+
+        return written
+
+
+_HTML_TEMPLATE = """<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>__TITLE__</title>
+<style>
+  body { font-family: system-ui, sans-serif; margin: 2rem; color: #1f2328; }
+  h1 { font-size: 1.4rem; }
+  .filters button { margin: 0 0.4rem 0.8rem 0; padding: 0.3rem 0.8rem;
+    border: 1px solid #d0d7de; border-radius: 1rem; background: #f6f8fa; cursor: pointer; }
+  .filters button.off { opacity: 0.4; text-decoration: line-through; }
+  table { border-collapse: collapse; width: 100%; }
+  th, td { border-bottom: 1px solid #d0d7de; padding: 0.4rem; text-align: left; vertical-align: top; }
+  .sev { font-weight: 600; text-transform: uppercase; }
+  .critical { color: #a40e26; } .high { color: #bc4c00; } .medium { color: #9a6700; }
+  .low { color: #0969da; } .info { color: #57606a; }
+  pre { background: #f6f8fa; padding: 0.5rem; overflow-x: auto; }
+  h2 { font-size: 1.1rem; margin-top: 2rem; }
+</style>
+</head>
+<body>
+<h1>__TITLE__</h1>
+<p id="meta"></p>
+<div class="filters" id="filters"></div>
+<table>
+  <thead><tr><th>Severity</th><th>Finding</th><th>Location</th><th>Recommendation</th></tr></thead>
+  <tbody id="findings"></tbody>
+</table>
+<section id="test-only" hidden>
+<h2>Test-only secrets (severity reduced)</h2>
+<table>
+  <thead><tr><th>Severity</th><th>Finding</th><th>Location</th><th>Recommendation</th></tr></thead>
+  <tbody id="test-only-findings"></tbody>
+</table>
+</section>
+<script type="application/json" id="report-data">__REPORT_JSON__</script>
+<script>
+(function () {
+  var report = JSON.parse(document.getElementById("report-data").textContent);
+  var testOnly = report.test_only_findings || [];
+  var severities = ["critical", "high", "medium", "low", "info"];
+  var hidden = {};
+
+  function cell(row, text, cls) {
+    var td = document.createElement("td");
+    td.textContent = text;
+    if (cls) { td.className = cls; }
+    row.appendChild(td);
+    return td;
+  }
+
+  function renderRows(bodyId, findings) {
+    var body = document.getElementById(bodyId);
+    body.textContent = "";
+    findings.forEach(function (f) {
+      if (hidden[f.severity]) { return; }
+      var row = document.createElement("tr");
+      cell(row, f.severity, "sev " + f.severity);
+      var main = cell(row, f.title);
+      var desc = document.createElement("div");
+      desc.textContent = f.description;
+      main.appendChild(desc);
+      if (f.evidence && Object.keys(f.evidence).length) {
+        var details = document.createElement("details");
+        var summary = document.createElement("summary");
+        summary.textContent = "Snippet";
+        var pre = document.createElement("pre");
+        pre.textContent = f.evidence.line_content || JSON.stringify(f.evidence, null, 2);
+        details.appendChild(summary);
+        details.appendChild(pre);
+        main.appendChild(details);
+      }
+      cell(row, f.file_path ? f.file_path + (f.line_number ? ":" + f.line_number : "") : "");
+      cell(row, f.recommendation || "");
+      body.appendChild(row);
+    });
+  }
+
+  function render() {
+    renderRows("findings", report.findings);
+    renderRows("test-only-findings", testOnly);
+  }
+
+  var filters = document.getElementById("filters");
+  severities.forEach(function (sev) {
+    var count = report.findings.concat(testOnly)
+      .filter(function (f) { return f.severity === sev; }).length;
+    var button = document.createElement("button");
+    button.className = sev;
+    button.textContent = sev + " (" + count + ")";
+    button.onclick = function () {
+      hidden[sev] = !hidden[sev];
+      button.classList.toggle("off", hidden[sev]);
+      render();
+    };
+    filters.appendChild(button);
+  });
+
+  document.getElementById("meta").textContent =
+    report.script_name + " | " + report.timestamp + " | " + report.findings.length + " findings" +
+    (testOnly.length ? " + " + testOnly.length + " test-only" : "");
+  document.getElementById("test-only").hidden = !testOnly.length;
+  render();
+})();
+</script>
+</body>
+</html>
+"""
 
 
 class ResultFormatter:
@@ -275,8 +1083,23 @@ class ResultFormatter:
         return combined
 
     @staticmethod
-    def format_console_output(result: AnalysisResult) -> str:
-        """Format result for console display."""
+    def format_console_output(
+        result: AnalysisResult,
+        line_ending: LineEnding = LineEnding.LF,
+        min_severity: str = "info",
+        symbols: Optional[dict[str, str]] = None,
+        color: Optional[bool] = False,
+    ) -> str:
+        """
+        Format result for console display, listing findings >= min_severity.
+
+        Each finding is prefixed with a severity symbol (``SEVERITY_SYMBOLS``,
+        overridable per severity via ``symbols``) whether or not color is on.
+        ``color=None`` decides from the terminal and ``NO_COLOR``.
+        """
+        prefixes = {**SEVERITY_SYMBOLS, **(symbols or {})}
+        if color is None:
+            color = console_color_enabled()
         lines = []
         lines.append(
             f"=== {result.analysis_type.value.upper()} ANALYSIS: {result.script_name} ==="
@@ -287,11 +1110,17 @@ class ResultFormatter:
 
         if not result.success:
             lines.append(f"❌ FAILED: {result.error_message}")
-            return "\n".join(lines)
+            return line_ending.value.join(lines)
 
-        summary = result.get_summary()
+        findings = result.findings_at_or_above(min_severity)
+        summary = {severity.value: 0 for severity in Severity}
+        for finding in findings:
+            summary[finding.severity.value] += 1
         lines.append("✅ SUCCESS")
         lines.append(f"Findings: {sum(summary.values())} total")
+        hidden = len(result.findings) - len(findings)
+        if hidden:
+            lines.append(f"  ({hidden} below {min_severity} hidden)")
 
         for severity in ["critical", "high", "medium", "low", "info"]:
             count = summary.get(severity, 0)
@@ -307,7 +1136,21 @@ class ResultFormatter:
                     f"  {emoji.get(severity, '•')} {severity.upper()}: {count}"
                 )
 
-        return "\n".join(lines)
+        def finding_line(finding: Finding) -> str:
+            severity = finding.severity.value
+            label = prefixes[severity]
+            if color:
+                label = f"{_SEVERITY_COLORS[severity]}{label}{_RESET_COLOR}"
+            location = f"{finding.file_path}:{finding.line_number}"
+            return f"  {label} {location} {finding.title}"
+
+        test_only = [f for f in findings if f.evidence.get("test_only")]
+        lines.extend(finding_line(f) for f in findings if f not in test_only)
+        if test_only:
+            lines.append(f"Test-only secrets ({len(test_only)}, severity reduced):")
+            lines.extend(finding_line(f) for f in test_only)
+
+        return line_ending.value.join(lines)
 
 
 def main():
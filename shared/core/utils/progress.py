@@ -0,0 +1,135 @@
+#!/usr/bin/env python3
+"""
+Scan progress reporting.
+
+Analyzers publish `ScanEvent`s through `BaseAnalyzer.on_scan_event`.
+`ProgressBar` is one consumer: it draws files processed, findings so far and
+an ETA on a single stderr line.
+
+Events go through a queue to one render thread, so events published from
+worker threads never interleave partial lines. The bar turns itself off when
+the stream is not a terminal, leaving CI logs and redirected output clean.
+"""
+
+import queue
+import sys
+import threading
+import time
+from dataclasses import dataclass
+from typing import Optional, TextIO
+
+BAR_WIDTH = 24
+
+# Redraw at most this often; events in between only update the counts
+_REFRESH_SECONDS = 0.1
+
+
+@dataclass(frozen=True)
+class ScanEvent:
+    """Progress of one scan: "started", "progress" or "finished"."""
+
+    kind: str
+    files_done: int = 0
+    files_total: Optional[int] = None  # None when the tool discovers files itself
+    findings: int = 0
+
+
+def _format_duration(seconds: float) -> str:
+    minutes, seconds = divmod(int(seconds), 60)
+    hours, minutes = divmod(minutes, 60)
+    return f"{hours}:{minutes:02d}:{seconds:02d}"
+
+
+def render_progress(event: ScanEvent, elapsed: float) -> str:
+    """Render one progress line for event, elapsed seconds after the start."""
+    total = event.files_total
+    done = min(event.files_done, total or 0)
+    if event.kind == "finished":
+        timing = f"done in {_format_duration(elapsed)}"
+    elif not total:
+        timing = f"{_format_duration(elapsed)} elapsed"
+    elif done:
+        timing = f"ETA {_format_duration(elapsed / done * (total - done))}"
+    else:
+        timing = "ETA --:--:--"
+
+    found = f"{event.findings} findings"
+    if not total:
+        return f"Scanning... {found}, {timing}"
+    filled = BAR_WIDTH * done // total
+    bar = "#" * filled + "-" * (BAR_WIDTH - filled)
+    return f"[{bar}] {done}/{total} files, {found}, {timing}"
+
+
+class ProgressBar:
+    """
+    Event consumer that draws a progress line on a terminal stream.
+
+    Use as a context manager and pass the instance as the event hook:
+
+        with ProgressBar() as bar:
+            analyzer.on_scan_event = bar
+            result = analyzer.analyze(target)
+    """
+
+    def __init__(
+        self, stream: Optional[TextIO] = None, enabled: Optional[bool] = None
+    ):
+        self.stream = stream or sys.stderr
+        if enabled is None:
+            isatty = getattr(self.stream, "isatty", None)
+            enabled = bool(isatty and isatty())
+        self.enabled = enabled
+        self._events: "queue.Queue[Optional[ScanEvent]]" = queue.Queue()
+        self._thread: Optional[threading.Thread] = None
+        self._started_at = time.monotonic()
+        self._width = 0
+
+    def __call__(self, event: ScanEvent) -> None:
+        if self.enabled:
+            self._events.put(event)
+
+    def __enter__(self) -> "ProgressBar":
+        if self.enabled:
+            self._thread = threading.Thread(
+                target=self._render_loop, name="scan-progress", daemon=True
+            )
+            self._thread.start()
+        return self
+
+    def __exit__(self, *_exc) -> None:
+        self.close()
+
+    def close(self) -> None:
+        """Draw the last state, end the line and stop the render thread."""
+        if self._thread is None:
+            return
+        self._events.put(None)
+        self._thread.join()
+        self._thread = None
+
+    def _render_loop(self) -> None:
+        latest: Optional[ScanEvent] = None
+        drawn_at = 0.0
+        while True:
+            event = self._events.get()
+            if event is None:
+                break
+            if event.kind == "started":
+                self._started_at = time.monotonic()
+            latest = event
+            now = time.monotonic()
+            if event.kind == "finished" or now - drawn_at >= _REFRESH_SECONDS:
+                self._draw(latest)
+                drawn_at = now
+        if latest is not None:
+            self._draw(latest)
+            self.stream.write("\n")
+            self.stream.flush()
+
+    def _draw(self, event: ScanEvent) -> None:
+        line = render_progress(event, time.monotonic() - self._started_at)
+        # Pad over the previous line, which may have been longer
+        self.stream.write("\r" + line.ljust(self._width))
+        self.stream.flush()
+        self._width = len(line)
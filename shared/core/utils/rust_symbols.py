@@ -0,0 +1,141 @@
+#!/usr/bin/env python3
+"""
+Rust symbol lookup for symbol-scoped scans.
+
+Finds the line span of a named `fn` in Rust source without a full parser:
+comments and string/char literals are blanked out (newlines kept, so line
+numbers are unchanged), then items are located by regex and their bodies by
+brace matching. Names may be qualified by enclosing `impl`, `trait` or `mod`
+blocks, e.g. `Server::handle` or `web::server::start`. The same approach
+finds the items gated behind `#[cfg(test)]`, every function's span and the
+function enclosing a line.
+"""
+
+import re
+from typing import Optional
+
+_LITERAL_OR_COMMENT = re.compile(
+    r"""
+      //[^\n]*
+    | /\*.*?\*/
+    | b?r(?P<hashes>\#*)".*?"(?P=hashes)
+    | b?"(?:\\.|[^"\\])*"
+    | b?'(?:\\.|[^'\\])'
+    """,
+    re.DOTALL | re.VERBOSE,
+)
+
+
+def _blank(match: re.Match) -> str:
+    return re.sub(r"[^\n]", " ", match.group(0))
+
+
+def _block_end(code: str, open_brace: int) -> int:
+    """Return the offset just past the brace that closes code[open_brace]."""
+    depth = 0
+    for offset in range(open_brace, len(code)):
+        if code[offset] == "{":
+            depth += 1
+        elif code[offset] == "}":
+            depth -= 1
+            if depth == 0:
+                return offset + 1
+    return len(code)
+
+
+def _body_start(code: str, offset: int, end: int) -> int:
+    """Offset of the item body's `{` after offset, or -1 for a `;` declaration."""
+    depth = 0
+    for offset in range(offset, end):
+        char = code[offset]
+        if char in "([":
+            depth += 1
+        elif char in ")]":
+            depth -= 1
+        elif depth == 0 and char in "{;":
+            return offset if char == "{" else -1
+    return -1
+
+
+def _blocks(code: str, header: str, start: int, end: int) -> list[tuple[int, int]]:
+    """Spans of the items in code[start:end] whose header matches the regex."""
+    spans = []
+    for match in re.compile(header).finditer(code, start, end):
+        body = _body_start(code, match.end(), end)
+        if body >= 0:
+            spans.append((match.start(), _block_end(code, body)))
+    return spans
+
+
+def _line_span(code: str, start: int, end: int) -> tuple[int, int]:
+    """1-based (first, last) lines of code[start:end]."""
+    line_start = code.rfind("\n", 0, start) + 1
+    return code.count("\n", 0, line_start) + 1, code.count("\n", 0, end - 1) + 1
+
+
+def find_rust_function(source: str, name: str) -> tuple[int, int]:
+    """
+    Return the 1-based (first, last) lines of the function called name.
+
+    Raises
+    ------
+        ValueError: If no function with a body matches, or several do
+    """
+    code = _LITERAL_OR_COMMENT.sub(_blank, source)
+    *containers, function = name.split("::")
+    scopes = [(0, len(code))]
+    for container in containers:
+        segment = re.escape(container)
+        header = (
+            rf"\bimpl\b[^{{;]*?\b{segment}\b[^{{;]*"
+            rf"|\b(?:mod|trait)\s+{segment}\b[^{{;]*"
+        )
+        scopes = [
+            span for start, end in scopes for span in _blocks(code, header, start, end)
+        ]
+
+    header = rf"\bfn\s+{re.escape(function)}\b"
+    spans: list[tuple[int, int]] = []
+    for start, end in scopes:
+        for span in _blocks(code, header, start, end):
+            if span not in spans:
+                spans.append(span)
+    if not spans:
+        raise ValueError(f"Function {name!r} not found")
+    if len(spans) > 1:
+        raise ValueError(
+            f"Function {name!r} is ambiguous ({len(spans)} matches); "
+            "qualify it with its impl or module"
+        )
+
+    return _line_span(code, *spans[0])
+
+
+def cfg_test_spans(source: str) -> list[tuple[int, int]]:
+    """Return the 1-based (first, last) lines of every `#[cfg(test)]` item body."""
+    code = _LITERAL_OR_COMMENT.sub(_blank, source)
+    header = r"#\s*\[\s*cfg\s*\(\s*test\s*\)\s*\]"
+    return [_line_span(code, *span) for span in _blocks(code, header, 0, len(code))]
+
+
+def rust_function_spans(source: str) -> list[tuple[str, int, int]]:
+    """Return (name, first, last) 1-based lines of every `fn` body, in source order."""
+    code = _LITERAL_OR_COMMENT.sub(_blank, source)
+    spans = []
+    for match in re.finditer(r"\bfn\s+(\w+)", code):
+        body = _body_start(code, match.end(), len(code))
+        if body < 0:
+            continue
+        first, last = _line_span(code, match.start(), _block_end(code, body))
+        spans.append((match.group(1), first, last))
+    return spans
+
+
+def enclosing_rust_function(source: str, line_number: int) -> Optional[str]:
+    """Return the name of the innermost `fn` whose body spans line_number."""
+    enclosing = None
+    for name, first, last in rust_function_spans(source):
+        # Later headers start deeper, so the last match wins
+        if first <= line_number <= last:
+            enclosing = name
+    return enclosing
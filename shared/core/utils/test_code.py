@@ -0,0 +1,77 @@
+#!/usr/bin/env python3
+"""
+Test-code classification for secret findings.
+
+Fixtures and examples legitimately contain secrets, but a real key can hide
+in a test file too, so those findings are downgraded rather than dropped. A
+location counts as test code when its path relative to the scan root has a
+`test`, `tests`, `benches` or `examples` component, a component starting
+with `test_`, or a file name ending in `_test` (`integration_test.rs`), or
+when it lies inside a Rust `#[cfg(test)]` item. Whole components are
+matched, so `attestation/` and `latest/` are not test code.
+
+Paths are judged relative to the scan root so that a checkout living under,
+say, `~/test-projects/` does not turn every finding into test code; files
+outside the root are judged by name alone. Relative finding paths that do
+not exist from the working directory resolve against the root.
+"""
+
+from pathlib import Path
+from typing import Optional, Union
+
+from .rust_symbols import cfg_test_spans
+
+# Path components that hold test code, benchmarks or example programs
+TEST_DIRECTORIES = {"test", "tests", "benches", "examples"}
+
+# Findings in test code drop one severity level
+TEST_ONLY_SEVERITY = {
+    "critical": "high",
+    "high": "medium",
+    "medium": "low",
+    "low": "info",
+    "info": "info",
+}
+
+
+def is_test_path(relative_path: Union[str, Path]) -> bool:
+    """Return True if a path component or the file name marks test code."""
+    path = Path(relative_path)
+    parts = [part.lower() for part in path.parts]
+    return path.stem.lower().endswith("_test") or any(
+        part in TEST_DIRECTORIES or part.startswith("test_") for part in parts
+    )
+
+
+class TestCodeClassifier:
+    """Decides whether a file/line location is test code, caching per file."""
+
+    __test__ = False  # Not a pytest test class despite its name
+
+    def __init__(self, root: Union[str, Path] = "."):
+        root_path = Path(root).resolve()
+        self.root = root_path.parent if root_path.is_file() else root_path
+        self._cfg_test: dict[Path, list[tuple[int, int]]] = {}
+
+    def is_test_code(self, file_path: str, line_number: Optional[int]) -> bool:
+        path = Path(file_path)
+        if not path.is_absolute() and not path.exists():
+            path = self.root / path
+        path = path.resolve()
+        if path.is_relative_to(self.root):
+            relative = path.relative_to(self.root)
+        else:
+            relative = Path(path.name)
+        if is_test_path(relative):
+            return True
+        if path.suffix != ".rs" or not line_number:
+            return False
+
+        if path not in self._cfg_test:
+            try:
+                source = path.read_text(encoding="utf-8", errors="ignore")
+            except OSError:
+                source = ""
+            self._cfg_test[path] = cfg_test_spans(source)
+        spans = self._cfg_test[path]
+        return any(first <= line_number <= last for first, last in spans)
@@ -0,0 +1,77 @@
+#!/usr/bin/env python3
+
+import io
+import json
+import stat
+import subprocess
+import tarfile
+import zipfile
+from pathlib import Path
+
+import analyzers.security.semgrep_analyzer as semgrep_module
+import pytest
+from analyzers.security.semgrep_analyzer import SemgrepAnalyzer
+from core.base.analyzer_base import AnalyzerConfig
+from core.base.archive_utils import extract_archive
+
+_VULNERABLE_RS = b"""use std::process::Command;
+
+pub fn run(user_input: String) {
+    Command::new("sh").arg("-c").arg(&user_input).output().unwrap();
+}
+"""
+
+
+def _fake_semgrep(cmd, **_kwargs):
+    if "--version" in cmd:
+        return subprocess.CompletedProcess(cmd, 0, stdout="1.50.0", stderr="")
+    results = [
+        {
+            "check_id": "rust-command-injection",
+            "path": str(path),
+            "start": {"line": 4},
+            "extra": {"severity": "ERROR", "lines": "Command::new"},
+        }
+        for path in Path(cmd[-1]).rglob("*.rs")
+        if b"Command::new" in path.read_bytes()
+    ]
+    payload = json.dumps({"results": results})
+    return subprocess.CompletedProcess(cmd, 0, stdout=payload, stderr="")
+
+
+def test_tarball_findings_are_relative_to_archive_root(tmp_path: Path, monkeypatch):
+    archive = tmp_path / "artifact.tar.gz"
+    with tarfile.open(archive, "w:gz") as tf:
+        info = tarfile.TarInfo("app/src/main.rs")
+        info.size = len(_VULNERABLE_RS)
+        tf.addfile(info, io.BytesIO(_VULNERABLE_RS))
+    monkeypatch.setattr(semgrep_module.subprocess, "run", _fake_semgrep)
+
+    analyzer = SemgrepAnalyzer(AnalyzerConfig(target_path=str(archive)))
+    result = analyzer.analyze_archive(str(archive))
+
+    assert result.target_path == str(archive)
+    assert [f.file_path for f in result.findings] == ["app/src/main.rs"]
+
+
+def test_extraction_rejects_zip_slip(tmp_path: Path):
+    archive = tmp_path / "evil.zip"
+    with zipfile.ZipFile(archive, "w") as zf:
+        zf.writestr("../../escaped.rs", "fn main() {}")
+
+    with pytest.raises(ValueError, match="outside extraction root"):
+        extract_archive(archive, tmp_path / "out")
+    assert not (tmp_path / "escaped.rs").exists()
+
+
+def test_extraction_skips_zip_symlinks(tmp_path: Path):
+    archive = tmp_path / "links.zip"
+    with zipfile.ZipFile(archive, "w") as zf:
+        link = zipfile.ZipInfo("app/secrets.rs")
+        link.external_attr = (stat.S_IFLNK | 0o777) << 16
+        zf.writestr(link, "/etc/passwd")
+        zf.writestr("app/main.rs", "fn main() {}")
+
+    root = extract_archive(archive, tmp_path / "out")
+
+    assert sorted(p.name for p in (root / "app").iterdir()) == ["main.rs"]
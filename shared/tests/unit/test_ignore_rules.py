@@ -0,0 +1,65 @@
+#!/usr/bin/env python3
+
+from pathlib import Path
+
+from core.base.ignore_rules import ScanIgnore
+
+
+def _touch(path: Path) -> Path:
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_text("", encoding="utf-8")
+    return path
+
+
+def test_directory_pattern_ignores_whole_subtree(tmp_path: Path):
+    (tmp_path / ".scannerignore").write_text(
+        "# fixtures\ntest_codebase/\n", encoding="utf-8"
+    )
+    vulnerable = _touch(tmp_path / "test_codebase" / "test-rust" / "src" / "main.rs")
+    app = _touch(tmp_path / "src" / "main.rs")
+
+    ignore = ScanIgnore(tmp_path)
+
+    assert ignore.is_ignored(vulnerable)
+    assert not ignore.is_ignored(app)
+
+
+def test_globs_anchoring_and_negation(tmp_path: Path):
+    (tmp_path / ".scannerignore").write_text(
+        "*.min.js\n/build\n**/generated/*.rs\n!keep.min.js\n", encoding="utf-8"
+    )
+    ignore = ScanIgnore(tmp_path)
+
+    assert ignore.is_ignored(_touch(tmp_path / "web" / "app.min.js"))
+    assert not ignore.is_ignored(_touch(tmp_path / "web" / "keep.min.js"))
+    assert ignore.is_ignored(_touch(tmp_path / "build" / "out.rs"))
+    assert not ignore.is_ignored(_touch(tmp_path / "src" / "build" / "out.rs"))
+    assert ignore.is_ignored(_touch(tmp_path / "a" / "b" / "generated" / "x.rs"))
+
+
+def test_nested_ignore_file_applies_only_to_its_subtree(tmp_path: Path):
+    _touch(tmp_path / "crates" / "api" / ".scannerignore").write_text(
+        "fixtures.rs\n", encoding="utf-8"
+    )
+    ignore = ScanIgnore(tmp_path)
+
+    api_fixture = _touch(tmp_path / "crates" / "api" / "src" / "fixtures.rs")
+    assert ignore.is_ignored(api_fixture)
+    assert not ignore.is_ignored(_touch(tmp_path / "crates" / "web" / "fixtures.rs"))
+
+
+def test_single_file_root_reads_only_the_ignore_file_beside_it(
+    tmp_path: Path, monkeypatch
+):
+    (tmp_path / ".scannerignore").write_text("generated.rs\n", encoding="utf-8")
+    generated = _touch(tmp_path / "generated.rs")
+    _touch(tmp_path / "vendor" / ".scannerignore").write_text("*\n", encoding="utf-8")
+
+    def no_walk(*_args):
+        raise AssertionError("single-file root must not walk the directory")
+
+    monkeypatch.setattr(Path, "rglob", no_walk)
+    ignore = ScanIgnore(generated)
+
+    assert ignore.is_ignored(generated)
+    assert len(ignore.patterns) == 1
@@ -0,0 +1,349 @@
+#!/usr/bin/env python3
+
+import json
+from pathlib import Path
+
+import pytest
+from core.base.analyzer_base import AnalyzerConfig
+from core.utils.output_formatter import (
+    AnalysisResult,
+    Finding,
+    FindingKey,
+    LineEnding,
+    ResultFormatter,
+    Severity,
+)
+from core.utils.test_code import is_test_path
+
+
+def _security_result() -> AnalysisResult:
+    result = ResultFormatter.create_security_result("semgrep_analyzer.py", "test-rust")
+    for idx, (severity, description) in enumerate(
+        [
+            ("critical", "Command injection via sh -c with user input"),
+            ("high", "Hardcoded API key in constant API_KEY"),
+            ("info", "Panic on unwrap can lead to denial of service"),
+        ],
+        start=1,
+    ):
+        result.add_finding(
+            ResultFormatter.create_finding(
+                ResultFormatter.FindingInput(
+                    finding_id=f"SEC{idx:03d}",
+                    title=f"Finding {idx}",
+                    description=description,
+                    severity=severity,
+                    file_path="src/main.rs",
+                    line_number=idx * 10,
+                    recommendation="Fix it",
+                    evidence={"line_content": "Command::new(\"sh\")"},
+                )
+            )
+        )
+    return result
+
+
+def test_to_html_embeds_every_finding_message():
+    result = _security_result()
+    page = result.to_html(min_severity="info")
+
+    assert page.startswith("<!DOCTYPE html>")
+    assert page.encode("utf-8").decode("utf-8") == page
+    for finding in result.findings:
+        assert finding.description in page
+    # Fully offline: no external scripts or stylesheets
+    assert "<script src" not in page
+    assert "<link" not in page
+
+
+def test_to_html_escapes_script_terminators():
+    result = _security_result()
+    result.findings[0].description = "payload </script><script>alert(1)</script>"
+    page = result.to_html()
+
+    assert page.count("</script>") == 2
+
+
+def test_write_all_writes_one_file_per_format(tmp_path: Path):
+    out_dir = tmp_path / "artifacts" / "security"
+    written = _security_result().write_all(str(out_dir), ["json", "html"])
+
+    assert sorted(p.name for p in out_dir.iterdir()) == ["report.html", "report.json"]
+    assert written == [out_dir / "report.json", out_dir / "report.html"]
+
+
+def test_write_all_rejects_unknown_format_before_writing(tmp_path: Path):
+    with pytest.raises(ValueError, match="pdf"):
+        _security_result().write_all(str(tmp_path / "out"), ["json", "pdf"])
+    assert not (tmp_path / "out").exists()
+
+
+def test_write_all_reports_write_errors(tmp_path: Path):
+    blocker = tmp_path / "not-a-dir"
+    blocker.write_text("", encoding="utf-8")
+    with pytest.raises(OSError, match="Failed to write reports"):
+        _security_result().write_all(str(blocker), ["json"])
+
+
+def test_text_outputs_use_requested_line_ending_consistently(tmp_path: Path):
+    result = _security_result()
+    console = ResultFormatter.format_console_output(result, LineEnding.CRLF)
+
+    assert console.count("\r\n") == console.count("\n") > 0
+    for path in result.write_all(
+        str(tmp_path),
+        ["json", "html", "markdown", "console"],
+        line_ending=LineEnding.CRLF,
+    ):
+        data = path.read_bytes()
+        assert data.count(b"\r\n") == data.count(b"\n") > 0, path.name
+        assert not data.startswith(b"\xef\xbb\xbf"), path.name
+
+
+def test_lf_is_default_line_ending(tmp_path: Path):
+    for path in _security_result().write_all(
+        str(tmp_path), ["json", "markdown", "console"]
+    ):
+        assert b"\r" not in path.read_bytes()
+
+
+def test_console_and_json_min_severity_are_independent(tmp_path: Path):
+    result = _security_result()
+    json_path, console_path = result.write_all(
+        str(tmp_path),
+        ["json", "console"],
+        min_severity="info",
+        format_min_severity={"console": "high"},
+    )
+
+    archived = json.loads(json_path.read_text(encoding="utf-8"))
+    console = console_path.read_text(encoding="utf-8")
+
+    assert "info" in [f["severity"] for f in archived["findings"]]
+    assert "src/main.rs:30" not in console
+    assert "src/main.rs:10" in console
+    assert "1 below high hidden" in console
+
+
+def test_by_crate_groups_findings_by_cargo_package(tmp_path: Path):
+    (tmp_path / "Cargo.toml").write_text(
+        '[workspace]\nmembers = ["api", "worker"]\n', encoding="utf-8"
+    )
+    for crate in ("api", "worker"):
+        (tmp_path / crate / "src").mkdir(parents=True)
+        (tmp_path / crate / "Cargo.toml").write_text(
+            f'[package]\nname = "{crate}-service"\nversion = "0.1.0"\n',
+            encoding="utf-8",
+        )
+        (tmp_path / crate / "src" / "main.rs").write_text("", encoding="utf-8")
+
+    result = ResultFormatter.create_security_result("semgrep.py", str(tmp_path))
+    for idx, (crate, severity) in enumerate(
+        [("api", "critical"), ("worker", "high"), ("api", "medium")], start=1
+    ):
+        result.add_finding(
+            ResultFormatter.create_finding(
+                ResultFormatter.FindingInput(
+                    finding_id=f"SEC{idx:03d}",
+                    title=f"Finding {idx}",
+                    description="Command injection",
+                    severity=severity,
+                    file_path=f"{crate}/src/main.rs",
+                    line_number=idx,
+                    recommendation="Fix it",
+                )
+            )
+        )
+
+    groups = result.by_crate()
+
+    assert [g["crate"] for g in groups] == ["api-service", "worker-service"]
+    assert [len(g["findings"]) for g in groups] == [2, 1]
+    assert groups[0]["summary"]["critical"] == 1
+    assert groups[0]["summary"]["medium"] == 1
+    assert groups[1]["manifest"] == str((tmp_path / "worker" / "Cargo.toml").resolve())
+
+
+def test_write_json_chunks_pages_findings_with_manifest(tmp_path: Path):
+    result = ResultFormatter.create_security_result("semgrep.py", "test-rust")
+    for idx in range(250):
+        result.add_finding(
+            ResultFormatter.create_finding(
+                ResultFormatter.FindingInput(
+                    finding_id=f"SEC{idx:03d}",
+                    title=f"Finding {idx}",
+                    description="Command injection",
+                    severity="high",
+                    file_path="src/main.rs",
+                    line_number=idx + 1,
+                    recommendation="Fix it",
+                    evidence={"fingerprint": f"fp{idx:04d}"},
+                )
+            )
+        )
+
+    manifest_path = result.write_json_chunks(str(tmp_path / "chunks"), per_file=100)
+    manifest = json.loads(manifest_path.read_text(encoding="utf-8"))
+    pages = [
+        json.loads((manifest_path.parent / c["file"]).read_text(encoding="utf-8"))
+        for c in manifest["chunks"]
+    ]
+
+    assert manifest["total_findings"] == 250
+    assert [c["findings"] for c in manifest["chunks"]] == [100, 100, 50]
+    fingerprints = [f["evidence"]["fingerprint"] for p in pages for f in p["findings"]]
+    assert fingerprints == [f"fp{idx:04d}" for idx in range(250)]
+
+
+def test_to_osv_maps_findings_to_osv_shaped_records():
+    result = _security_result()
+    result.findings[0].evidence["fingerprint"] = "0123456789abcdef"
+
+    records = json.loads(result.to_osv(min_severity="info"))
+
+    assert [r["id"] for r in records] == [
+        "SCAN-0123456789abcdef",
+        "SCAN-SEC002",
+        "SCAN-SEC003",
+    ]
+    for record in records:
+        assert set(record) >= {"id", "modified", "summary", "severity", "affected"}
+        assert record["modified"].endswith("Z")
+        (affected,) = record["affected"]
+        assert affected["package"]["name"] == "src/main.rs"
+        (line_range,) = affected["ranges"]
+        assert [list(e) for e in line_range["events"]] == [
+            ["introduced"],
+            ["last_affected"],
+        ]
+    assert [r["severity"][0]["score"] for r in records] == [
+        "critical",
+        "high",
+        "negligible",
+    ]
+    assert records[1]["affected"][0]["ranges"][0]["events"][0]["introduced"] == "20"
+
+
+def test_console_severity_symbols_survive_no_color(monkeypatch):
+    monkeypatch.setenv("NO_COLOR", "1")
+    result = _security_result()
+
+    plain = ResultFormatter.format_console_output(result, color=None)
+    custom = ResultFormatter.format_console_output(
+        result, symbols={"critical": "!!"}, color=None
+    )
+    colored = ResultFormatter.format_console_output(result, color=True)
+
+    assert "\033[" not in plain
+    assert "  [CRIT] src/main.rs:10 Finding 1" in plain
+    assert "  [HIGH] src/main.rs:20 Finding 2" in plain
+    assert "  [INFO] src/main.rs:30 Finding 3" in plain
+    assert "  !! src/main.rs:10 Finding 1" in custom
+    assert "[CRIT]" in colored and "\033[" in colored
+
+
+def test_sarif_level_map_overrides_default_levels():
+    result = _security_result()
+    result.findings[1].severity = Severity.MEDIUM
+
+    def levels(sarif: str) -> list[str]:
+        return [r["level"] for r in json.loads(sarif)["runs"][0]["results"]]
+
+    assert levels(result.to_sarif(min_severity="info")) == ["error", "warning", "note"]
+    assert levels(
+        result.to_sarif(min_severity="info", level_map={"medium": "error"})
+    ) == ["error", "error", "note"]
+
+
+def test_sarif_level_map_rejects_unknown_levels():
+    with pytest.raises(ValueError, match="sarif_level_map"):
+        AnalyzerConfig(sarif_level_map={"medium": "fatal"})
+
+
+def test_merging_overlapping_findings_keeps_both_rule_ids_and_max_severity():
+    def finding(rule: str, severity: Severity, confidence: str) -> Finding:
+        return Finding(
+            finding_id="SEC001",
+            title=rule,
+            description=f"{rule} matched",
+            severity=severity,
+            file_path="src/main.rs",
+            line_number=27,
+            evidence={
+                "check_id": rule,
+                "fingerprint": f"{rule}-fp",
+                "confidence": confidence,
+            },
+        )
+
+    primary = finding("rust-command-injection", Severity.MEDIUM, "high")
+    overlapping = finding("rust-shell-exec", Severity.CRITICAL, "low")
+
+    merged = primary.merge(overlapping)
+
+    assert merged.severity is Severity.CRITICAL
+    assert merged.evidence["confidence"] == "high"
+    assert merged.evidence["also_matched"] == ["rust-shell-exec"]
+    assert merged.description == "rust-command-injection matched"
+    rule = "rust-command-injection"
+    assert merged.key == FindingKey(rule, f"{rule}-fp")
+    assert merged.key == primary.key and len({merged.key, primary.key}) == 1
+    assert merged.merge(primary).evidence["also_matched"] == ["rust-shell-exec"]
+
+
+def test_is_test_path_matches_whole_components_only():
+    for path in [
+        "tests/main.rs",
+        "crates/api/test/fixtures.rs",
+        "benches/parse.rs",
+        "src/test_utils.rs",
+        "src/integration_test.rs",
+        "examples/server.rs",
+    ]:
+        assert is_test_path(path), path
+    for path in [
+        "src/attestation/verify.rs",
+        "releases/latest/main.rs",
+        "src/example.rs",
+        "src/contest.rs",
+    ]:
+        assert not is_test_path(path), path
+
+
+def test_to_html_lists_test_only_findings_in_their_own_section():
+    result = _security_result()
+    result.findings[1].evidence["test_only"] = True
+
+    page = result.to_html(min_severity="info")
+    report = json.loads(
+        page.split('id="report-data">', 1)[1].split("</script>", 1)[0]
+    )
+
+    assert '<section id="test-only" hidden>' in page
+    assert 'renderRows("test-only-findings", testOnly)' in page
+    assert [f["description"] for f in report["test_only_findings"]] == [
+        "Hardcoded API key in constant API_KEY"
+    ]
+
+
+def test_write_all_writes_markdown_report(tmp_path: Path):
+    result = _security_result()
+    result.findings[0].description = "sh -c | pipes\nacross lines"
+    written = result.write_all(str(tmp_path), ["markdown"], min_severity="info")
+
+    assert written == [tmp_path / "report.md"]
+    report = written[0].read_text(encoding="utf-8")
+    assert report.startswith("# Security Report: test-rust")
+    row = "| critical | `src/main.rs:10` | Finding 1 | sh -c \\| pipes across lines |"
+    assert row in report
+    for finding in result.findings[1:]:
+        assert finding.description in report
+
+
+def test_markdown_uses_requested_line_ending_consistently():
+    result = _security_result()
+    result.findings[1].description = "Mixed\r\nline\nendings"
+
+    crlf = result.to_markdown(line_ending=LineEnding.CRLF)
+    assert crlf.count("\r\n") == crlf.count("\n") > 0
+    assert "\r" not in result.to_markdown()
@@ -0,0 +1,65 @@
+#!/usr/bin/env python3
+
+import io
+import json
+import subprocess
+from pathlib import Path
+
+import analyzers.security.detect_secrets_analyzer as detect_secrets_module
+from analyzers.security.detect_secrets_analyzer import DetectSecretsAnalyzer
+from core.base.analyzer_base import AnalyzerConfig
+from core.utils.progress import ProgressBar, ScanEvent, render_progress
+
+_FIXTURE_TREE = (
+    Path(__file__).resolve().parents[3]
+    / "test_codebase"
+    / "vulnerable-apps"
+    / "test-rust"
+)
+
+
+def _no_secrets(cmd, **_kwargs):
+    if "--version" in cmd:
+        return subprocess.CompletedProcess(cmd, 0, stdout="1.5.0", stderr="")
+    stdout = json.dumps({"results": {cmd[-1]: []}})
+    return subprocess.CompletedProcess(cmd, 0, stdout=stdout, stderr="")
+
+
+def test_progress_bar_completes_over_the_fixture_tree(monkeypatch):
+    monkeypatch.setattr(detect_secrets_module.subprocess, "run", _no_secrets)
+    config = AnalyzerConfig(
+        target_path=str(_FIXTURE_TREE), code_extensions={".rs"}, batch_size=4
+    )
+    analyzer = DetectSecretsAnalyzer(config)
+    events: list[ScanEvent] = []
+    stream = io.StringIO()
+
+    with ProgressBar(stream, enabled=True) as bar:
+        analyzer.on_scan_event = lambda event: (events.append(event), bar(event))
+        result = analyzer.analyze(str(_FIXTURE_TREE))
+
+    total = result.metadata["files_analyzed"]
+    assert total >= 20
+    assert [events[0].kind, events[-1].kind] == ["started", "finished"]
+    done = [event.files_done for event in events]
+    assert done == sorted(done) and done[-1] == total
+    final_line = stream.getvalue().rstrip("\n").split("\r")[-1]
+    found = events[-1].findings
+    assert f"] {total}/{total} files, {found} findings, done in" in final_line
+
+
+def test_progress_bar_is_silent_when_stream_is_not_a_terminal():
+    stream = io.StringIO()
+    with ProgressBar(stream) as bar:
+        bar(ScanEvent("started", files_total=2))
+        bar(ScanEvent("finished", files_done=2, files_total=2))
+
+    assert bar.enabled is False
+    assert stream.getvalue() == ""
+
+
+def test_render_progress_estimates_remaining_time():
+    line = render_progress(ScanEvent("progress", 10, 40, findings=3), elapsed=30)
+
+    assert line == "[######------------------] 10/40 files, 3 findings, ETA 0:01:30"
+    assert render_progress(ScanEvent("started"), elapsed=0).startswith("Scanning...")
@@ -0,0 +1,43 @@
+#!/usr/bin/env python3
+
+from pathlib import Path
+
+from core.base.report_cache import ReportCache
+
+_SOURCE = """fn read_config(path: String) -> String {
+    std::fs::read_to_string(path).unwrap()
+}
+
+fn run(cmd: String) {
+    std::process::Command::new("sh").arg(cmd).output().unwrap();
+}
+"""
+
+
+def test_editing_one_function_invalidates_only_its_summary(tmp_path: Path):
+    cache = ReportCache(tmp_path, "security")
+    calls: list[tuple[int, int]] = []
+
+    def summarize(first: int, last: int) -> list[str]:
+        calls.append((first, last))
+        return [f"lines {first}-{last}"]
+
+    first_run, first_computed = cache.function_summaries(
+        "src/lib.rs", _SOURCE, summarize
+    )
+    rerun, rerun_computed = cache.function_summaries("src/lib.rs", _SOURCE, summarize)
+    edited = _SOURCE.replace("arg(cmd)", 'arg("-c").arg(cmd)')
+    after_edit, edit_computed = cache.function_summaries(
+        "src/lib.rs", edited, summarize
+    )
+
+    assert [name for name, _ in first_run] == ["read_config", "run"]
+    assert (first_computed, rerun_computed, edit_computed) == (2, 0, 1)
+    assert rerun == first_run
+    assert calls == [(1, 3), (5, 7), (5, 7)]
+    assert after_edit[0] == first_run[0]
+    # A fresh cache object reads the summaries back from disk
+    _, reloaded_computed = ReportCache(tmp_path, "security").function_summaries(
+        "src/lib.rs", edited, summarize
+    )
+    assert reloaded_computed == 0
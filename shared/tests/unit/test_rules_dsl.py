@@ -0,0 +1,51 @@
+#!/usr/bin/env python3
+
+from pathlib import Path
+
+import pytest
+from analyzers.security.rules_dsl import (
+    compile_rule,
+    load_rule_manifest,
+    write_semgrep_config,
+)
+from tests.utils.rule_fixtures import RUST_FIXTURE_ROOT, run_rules, semgrep_available
+
+_EXAMPLE = (
+    Path(__file__).resolve().parents[2]
+    / "analyzers"
+    / "security"
+    / "rules"
+    / "dsl"
+    / "command-injection.yml"
+)
+
+
+def test_example_manifest_compiles_to_taint_rule():
+    (rule,) = load_rule_manifest(_EXAMPLE)
+
+    assert rule["id"] == "dsl-rust-command-injection"
+    assert rule["mode"] == "taint"
+    assert rule["severity"] == "ERROR"
+    sink_patterns = [sink["patterns"][0]["pattern"] for sink in rule["pattern-sinks"]]
+    assert "Command::new($TAINTED, ...)" in sink_patterns
+    assert "$RECEIVER.arg($TAINTED, ...)" in sink_patterns
+
+    with pytest.raises(ValueError, match="tainted_arg"):
+        compile_rule(
+            {
+                "id": "bad",
+                "message": "m",
+                "sinks": [{"method": "arg", "tainted_arg": -1}],
+            }
+        )
+
+
+def test_example_manifest_matches_command_injection_fixture(tmp_path):
+    config = write_semgrep_config([_EXAMPLE], tmp_path / "dsl.yml")
+    if not semgrep_available():
+        pytest.skip("semgrep not installed")
+
+    findings = run_rules(RUST_FIXTURE_ROOT / "web" / "server.rs", rules=config)
+
+    # .arg(cmd) in execute_command, where cmd comes from the JSON body
+    assert ("dsl-rust-command-injection", 26) in findings
@@ -0,0 +1,300 @@
+#!/usr/bin/env python3
+
+import io
+import json
+import subprocess
+import sys
+from pathlib import Path
+
+import analyzers.security.semgrep_analyzer as semgrep_module
+import pytest
+from core.cli import run_analyzer
+
+_INJECTION_SNIPPET = """use std::process::Command;
+
+fn run(user_input: &str) {
+    Command::new("sh").arg("-c").arg(user_input).output().unwrap();
+}
+"""
+
+
+def _fake_semgrep(cmd, **_kwargs):
+    if "--version" in cmd:
+        return subprocess.CompletedProcess(cmd, 0, stdout="1.50.0", stderr="")
+    results = []
+    ruleset = cmd[cmd.index("--config") + 1]
+    if ruleset.endswith("rust-security.yml") and cmd[-1].endswith(".rs"):
+        results.append(
+            {
+                "check_id": "rust-command-injection",
+                "path": cmd[-1],
+                "start": {"line": 4},
+                "extra": {"severity": "ERROR", "lines": "Command::new(\"sh\")"},
+            }
+        )
+    payload = json.dumps({"results": results})
+    return subprocess.CompletedProcess(cmd, 0, stdout=payload, stderr="")
+
+
+def test_stdin_source_reported_against_virtual_path(monkeypatch):
+    monkeypatch.setattr(semgrep_module.subprocess, "run", _fake_semgrep)
+    monkeypatch.setattr(sys, "stdin", io.StringIO(_INJECTION_SNIPPET))
+    stdout = io.StringIO()
+    monkeypatch.setattr(sys, "stdout", stdout)
+
+    exit_code = run_analyzer.main(
+        [
+            "--analyzer",
+            "security:semgrep",
+            "--stdin",
+            "--path",
+            "src/main.rs",
+            "--min-severity",
+            "low",
+        ]
+    )
+
+    findings = json.loads(stdout.getvalue())["findings"]
+    assert exit_code == 0
+    assert len(findings) == 1
+    assert findings[0]["file_path"] == "src/main.rs"
+    assert findings[0]["evidence"]["check_id"] == "rust-command-injection"
+
+
+def test_list_rules_dumps_catalog_with_active_config(monkeypatch):
+    stdout = io.StringIO()
+    monkeypatch.setattr(sys, "stdout", stdout)
+
+    exit_code = run_analyzer.main(
+        ["--list-rules", "--suppress-rules", "rust-unsafe-block"]
+    )
+
+    rules = {rule["id"]: rule for rule in json.loads(stdout.getvalue())}
+    assert exit_code == 0
+    injection = rules["rust-command-injection"]
+    assert injection["cwe"].startswith("CWE-78")
+    assert injection["severity"] == "critical"
+    assert injection["help_uri"].startswith("https://")
+    assert injection["enabled"] is True
+    assert rules["rust-unsafe-block"]["disabled_reason"] == "suppressed"
+    assert rules["rust-ignored-security-result"]["disabled_reason"] == "opt-in"
+
+
+def test_explain_prints_rule_details_and_lists_ids_for_unknown_rule(monkeypatch):
+    stdout, stderr = io.StringIO(), io.StringIO()
+    monkeypatch.setattr(sys, "stdout", stdout)
+    monkeypatch.setattr(sys, "stderr", stderr)
+
+    assert run_analyzer.main(["--explain", "command-injection"]) == 0
+    assert run_analyzer.main(["--explain", "no-such-rule"]) == 2
+
+    explanation = stdout.getvalue()
+    assert explanation.startswith("rust-command-injection (critical severity")
+    assert "CWE-78" in explanation
+    fix = explanation.split("Fix:", 1)[1].split("References:", 1)[0]
+    assert 'Command::new("ls")' in fix
+    assert "Unknown rule 'no-such-rule'" in stderr.getvalue()
+    assert "  rust-command-injection" in stderr.getvalue()
+    # Every bundled rule ships its own vulnerable/fixed examples
+    assert all(rule["example"] and rule["fix"] for rule in run_analyzer.list_rules())
+
+
+def test_staged_scan_reports_only_staged_lines(tmp_path, monkeypatch):
+    real_run = subprocess.run
+    git = ["git", "-c", "user.name=Dana Reviewer", "-c", "user.email=dana@example.com"]
+    src = tmp_path / "src"
+    src.mkdir()
+    (src / "old.rs").write_text("fn main() {}\nfn legacy(cmd: &str) {}\n")
+    real_run(["git", "init", "-q"], cwd=tmp_path, check=True)
+    real_run(["git", "add", "."], cwd=tmp_path, check=True)
+    real_run([*git, "commit", "-qm", "init"], cwd=tmp_path, check=True)
+    with (src / "old.rs").open("a") as old:
+        old.write("fn added() {}\nfn added_too(cmd: &str) {}\n")
+    (src / "new.rs").write_text(_INJECTION_SNIPPET)
+    (src / "untracked.rs").write_text(_INJECTION_SNIPPET)
+    real_run(["git", "add", "src/old.rs", "src/new.rs"], cwd=tmp_path, check=True)
+
+    scanned: list[str] = []
+
+    def run(cmd, **kwargs):
+        if cmd[0] == "git":
+            return real_run(cmd, **kwargs)
+        if "--version" in cmd:
+            return subprocess.CompletedProcess(cmd, 0, stdout="1.50.0", stderr="")
+        paths = [arg for arg in cmd if arg.endswith(".rs")]
+        scanned.extend(paths)
+        results = [
+            {
+                "check_id": "rust-command-injection",
+                "path": path,
+                "start": {"line": line},
+                "extra": {"severity": "ERROR", "lines": "Command::new"},
+            }
+            for path in paths
+            for line in (2, 4)
+        ]
+        payload = json.dumps({"results": results})
+        return subprocess.CompletedProcess(cmd, 0, stdout=payload, stderr="")
+
+    monkeypatch.setattr(semgrep_module.subprocess, "run", run)
+    # BaseAnalyzer skips any path containing "tmp", so scan relative to the repo
+    monkeypatch.chdir(tmp_path)
+    stdout = io.StringIO()
+    monkeypatch.setattr(sys, "stdout", stdout)
+
+    exit_code = run_analyzer.main(
+        ["--analyzer", "security:semgrep", "--staged", "--min-severity", "high"]
+    )
+
+    findings = json.loads(stdout.getvalue())["findings"]
+    assert exit_code == 1
+    # Line 2 of old.rs was already committed, so only its new line 4 is reported
+    assert sorted(set(scanned)) == ["src/new.rs", "src/old.rs"]
+    assert sorted((f["file_path"], f["line_number"]) for f in findings) == [
+        ("src/new.rs", 2),
+        ("src/new.rs", 4),
+        ("src/old.rs", 4),
+    ]
+
+
+def test_rule_manifest_rules_run_in_a_directory_scan(tmp_path, monkeypatch):
+    manifest = semgrep_module.RUST_RULES_PATH.parent / "dsl"
+    (tmp_path / "main.rs").write_text(_INJECTION_SNIPPET)
+    configs: list[str] = []
+
+    def run(cmd, **_kwargs):
+        if "--version" in cmd:
+            return subprocess.CompletedProcess(cmd, 0, stdout="1.50.0", stderr="")
+        paths = [cmd[i + 1] for i, arg in enumerate(cmd) if arg == "--config"]
+        configs.extend(paths)
+        rule_ids = {
+            line.split(":", 1)[1].strip()
+            for path in paths
+            for line in open(path).read().splitlines()
+            if line.startswith("- id:")
+        }
+        results = [
+            {
+                "check_id": "dsl-rust-command-injection",
+                "path": str(tmp_path / "main.rs"),
+                "start": {"line": 4},
+                "extra": {"severity": "ERROR", "lines": "Command::new(\"sh\")"},
+            }
+        ]
+        payload = json.dumps(
+            {"results": results if "dsl-rust-command-injection" in rule_ids else []}
+        )
+        return subprocess.CompletedProcess(cmd, 0, stdout=payload, stderr="")
+
+    monkeypatch.setattr(semgrep_module.subprocess, "run", run)
+    stdout = io.StringIO()
+    monkeypatch.setattr(sys, "stdout", stdout)
+
+    exit_code = run_analyzer.main(
+        [
+            "--analyzer",
+            "security:semgrep",
+            "--target",
+            str(tmp_path),
+            "--rule-manifest",
+            str(manifest / "command-injection.yml"),
+        ]
+    )
+
+    findings = json.loads(stdout.getvalue())["findings"]
+    assert exit_code == 0
+    assert [f["evidence"]["check_id"] for f in findings] == [
+        "dsl-rust-command-injection"
+    ]
+    assert any(path.endswith("rust-security.yml") for path in configs)
+    # The compiled manifest config is removed once Semgrep has exited
+    compiled = [path for path in configs if "semgrep-dsl-" in path]
+    assert compiled and not any(Path(p).exists() for p in compiled)
+
+
+def test_staged_scan_of_a_subdirectory_handles_quoted_paths(tmp_path, monkeypatch):
+    real_run = subprocess.run
+    git = ["git", "-c", "user.name=Dana Reviewer", "-c", "user.email=dana@example.com"]
+    sub = tmp_path / "sub"
+    sub.mkdir()
+    (tmp_path / "README.md").write_text("docs\n")
+    real_run(["git", "init", "-q"], cwd=tmp_path, check=True)
+    real_run(["git", "add", "."], cwd=tmp_path, check=True)
+    real_run([*git, "commit", "-qm", "init"], cwd=tmp_path, check=True)
+    for name in ("my file.rs", "café.rs"):
+        (sub / name).write_text(_INJECTION_SNIPPET)
+    (tmp_path / "outside.rs").write_text(_INJECTION_SNIPPET)
+    real_run(["git", "add", "."], cwd=tmp_path, check=True)
+
+    scanned: list[str] = []
+
+    def run(cmd, **kwargs):
+        if cmd[0] == "git":
+            return real_run(cmd, **kwargs)
+        if "--version" in cmd:
+            return subprocess.CompletedProcess(cmd, 0, stdout="1.50.0", stderr="")
+        paths = [arg for arg in cmd if arg.endswith(".rs")]
+        scanned.extend(paths)
+        results = [
+            {
+                "check_id": "rust-command-injection",
+                "path": path,
+                "start": {"line": 4},
+                "extra": {"severity": "ERROR", "lines": "Command::new"},
+            }
+            for path in paths
+        ]
+        payload = json.dumps({"results": results})
+        return subprocess.CompletedProcess(cmd, 0, stdout=payload, stderr="")
+
+    monkeypatch.setattr(semgrep_module.subprocess, "run", run)
+    # BaseAnalyzer skips any path containing "tmp", so scan relative to the repo
+    monkeypatch.chdir(tmp_path)
+    stdout = io.StringIO()
+    monkeypatch.setattr(sys, "stdout", stdout)
+
+    exit_code = run_analyzer.main(
+        ["--analyzer", "security:semgrep", "--staged", "--target", "sub"]
+    )
+
+    findings = json.loads(stdout.getvalue())["findings"]
+    assert exit_code == 1
+    expected = sorted(str(Path("sub") / name) for name in ("my file.rs", "café.rs"))
+    assert sorted(scanned) == expected
+    assert sorted(f["file_path"] for f in findings) == expected
+
+
+def test_line_ending_applies_to_printed_reports(monkeypatch):
+    monkeypatch.setattr(semgrep_module.subprocess, "run", _fake_semgrep)
+    for output_format in ("json", "console", "sarif", "osv", "html"):
+        monkeypatch.setattr(sys, "stdin", io.StringIO(_INJECTION_SNIPPET))
+        stdout = io.StringIO()
+        monkeypatch.setattr(sys, "stdout", stdout)
+
+        run_analyzer.main(
+            [
+                "--analyzer",
+                "security:semgrep",
+                "--stdin",
+                "--path",
+                "src/main.rs",
+                "--output-format",
+                output_format,
+                "--line-ending",
+                "crlf",
+            ]
+        )
+
+        printed = stdout.getvalue()
+        assert printed.endswith("\r\n"), output_format
+        assert printed.count("\n") == printed.count("\r\n"), output_format
+
+
+def test_json_chunk_size_requires_output_dir(monkeypatch):
+    stderr = io.StringIO()
+    monkeypatch.setattr(sys, "stderr", stderr)
+
+    with pytest.raises(SystemExit):
+        run_analyzer.main(["--analyzer", "security:semgrep", "--json-chunk-size", "5"])
+
+    assert "--json-chunk-size requires --output-dir" in stderr.getvalue()
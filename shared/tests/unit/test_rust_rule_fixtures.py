@@ -0,0 +1,45 @@
+#!/usr/bin/env python3
+
+from pathlib import Path
+
+import pytest
+from tests.utils.rule_fixtures import (
+    annotated_fixtures,
+    check_fixture,
+    compare,
+    parse_annotations,
+    semgrep_available,
+)
+
+_SOURCE = """\
+// ruleid: rust-a, rust-b
+let x = 1;
+// ok: rust-a
+let y = 2;
+let z = 3;
+"""
+
+
+def test_annotations_apply_to_next_code_line_and_compare_strictly_per_rule():
+    expected, clean = parse_annotations(_SOURCE)
+    assert expected == {("rust-a", 2), ("rust-b", 2)}
+    assert clean == {("rust-a", 4)}
+
+    fixture = Path("fixture.rs")
+    actual = {("rust-a", 2), ("rust-a", 4), ("rust-other", 5)}
+    problems = compare(fixture, expected, clean, actual)
+
+    assert problems == [
+        "fixture.rs:2: expected rust-b but it did not fire",
+        "fixture.rs:4: unexpected rust-a finding (annotated ok)",
+    ]
+
+
+def test_bundled_rust_fixtures_match_their_annotations():
+    fixtures = annotated_fixtures()
+    assert len(fixtures) >= 20
+    if not semgrep_available():
+        pytest.skip("semgrep not installed")
+
+    problems = [problem for fixture in fixtures for problem in check_fixture(fixture)]
+    assert not problems, "\n".join(problems)
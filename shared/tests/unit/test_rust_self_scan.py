@@ -0,0 +1,67 @@
+#!/usr/bin/env python3
+"""
+Golden self-scan of the bundled Rust fixture tree.
+
+Every finding the Rust rules report over test-rust/src is pinned, by rule and
+enclosing function, in snapshots/rust_self_scan.json. A rule change that adds
+or loses a finding anywhere fails here until the snapshot is regenerated on
+purpose:
+
+    UPDATE_SNAPSHOTS=1 python -m pytest tests/unit/test_rust_self_scan.py
+
+Regenerating records the semgrep version in the snapshot. A snapshot without
+one was written by hand from the `ruleid` annotations and fails the scan test
+until it has been regenerated.
+"""
+
+import json
+import os
+from pathlib import Path
+
+import pytest
+from tests.utils.rule_fixtures import (
+    annotated_entries,
+    self_scan,
+    semgrep_available,
+    semgrep_version,
+)
+
+SNAPSHOT = Path(__file__).parent / "snapshots" / "rust_self_scan.json"
+
+
+def _load_snapshot() -> dict:
+    return json.loads(SNAPSHOT.read_text(encoding="utf-8"))
+
+
+def _snapshot_findings() -> set[str]:
+    return set(_load_snapshot()["findings"])
+
+
+def test_snapshot_covers_every_ruleid_annotation():
+    missing = sorted(annotated_entries() - _snapshot_findings())
+    assert not missing, "\n".join(["annotated but not in snapshot:", *missing])
+
+
+def test_self_scan_matches_snapshot():
+    if not semgrep_available():
+        pytest.skip("semgrep not installed")
+
+    actual = self_scan()
+    if os.environ.get("UPDATE_SNAPSHOTS"):
+        snapshot = _load_snapshot()
+        snapshot["semgrep"] = semgrep_version()
+        snapshot["findings"] = sorted(actual)
+        SNAPSHOT.write_text(json.dumps(snapshot, indent=2) + "\n", encoding="utf-8")
+    assert _load_snapshot()["semgrep"], (
+        "snapshots/rust_self_scan.json was seeded from annotations and never"
+        " generated by semgrep; rerun with UPDATE_SNAPSHOTS=1, review and commit it."
+    )
+
+    expected = _snapshot_findings()
+    problems = [f"no longer reported: {entry}" for entry in sorted(expected - actual)]
+    problems.extend(f"newly reported: {entry}" for entry in sorted(actual - expected))
+    assert not problems, (
+        "\n".join(problems)
+        + "\nIf the change is intended, rerun with UPDATE_SNAPSHOTS=1 and commit"
+        " snapshots/rust_self_scan.json."
+    )
@@ -0,0 +1,176 @@
+#!/usr/bin/env python3
+"""
+Annotated rule fixtures for the Rust Semgrep rules.
+
+Fixtures are ordinary `.rs` files whose expectations live in comments, using
+the same annotations as `semgrep --test`:
+
+    // ruleid: rust-hardcoded-secret
+    const API_KEY: &str = "...";
+
+    // ok: rust-hardcoded-secret
+    let api_key = std::env::var("API_KEY")?;
+
+An annotation applies to the next non-annotation line, which must be where
+the finding starts. Several annotations may be stacked above one line.
+
+Checking is strict per rule: once a fixture mentions a rule id, every
+finding of that rule in the file must be annotated, and every `ruleid`
+annotation must be matched. Rules a fixture never mentions are ignored, so
+fixtures can share code without asserting on each other's findings.
+
+`self_scan` complements the per-file checks: it scans the whole tree and
+keys findings by rule and enclosing function, for the snapshot test that
+catches any change in what the rules report, annotated or not.
+"""
+
+import json
+import re
+import shutil
+import subprocess
+from pathlib import Path
+from typing import Union
+
+from core.utils.rust_symbols import enclosing_rust_function
+
+_SHARED_DIR = Path(__file__).resolve().parents[2]
+RUST_RULES = _SHARED_DIR / "analyzers" / "security" / "rules" / "rust-security.yml"
+RUST_FIXTURE_ROOT = (
+    _SHARED_DIR.parent / "test_codebase" / "vulnerable-apps" / "test-rust" / "src"
+)
+
+_ANNOTATION = re.compile(r"^\s*//\s*(ruleid|ok):\s*(.+?)\s*$")
+
+Expectation = tuple[str, int]
+
+
+def parse_annotations(source: str) -> tuple[set[Expectation], set[Expectation]]:
+    """Return (expected, allowed-to-be-clean) (rule_id, line) pairs."""
+    expected: set[Expectation] = set()
+    clean: set[Expectation] = set()
+    pending: list[tuple[str, str]] = []
+
+    for line_number, line in enumerate(source.splitlines(), start=1):
+        match = _ANNOTATION.match(line)
+        if match:
+            kind, rules = match.groups()
+            pending.extend((kind, rule.strip()) for rule in rules.split(","))
+            continue
+        for kind, rule in pending:
+            (expected if kind == "ruleid" else clean).add((rule, line_number))
+        pending = []
+
+    return expected, clean
+
+
+def annotated_fixtures(root: Union[str, Path] = RUST_FIXTURE_ROOT) -> list[Path]:
+    """Return every `.rs` file under root that carries at least one annotation."""
+    return [
+        path
+        for path in sorted(Path(root).rglob("*.rs"))
+        if any(_ANNOTATION.match(line) for line in path.read_text().splitlines())
+    ]
+
+
+def semgrep_available() -> bool:
+    return shutil.which("semgrep") is not None
+
+
+def semgrep_version() -> str:
+    result = subprocess.run(
+        ["semgrep", "--version"], capture_output=True, text=True, timeout=60
+    )
+    return result.stdout.strip()
+
+
+def run_rules(fixture: Path, rules: Path = RUST_RULES) -> set[Expectation]:
+    """Run the rule file over one fixture and return (rule_id, line) findings."""
+    result = subprocess.run(
+        ["semgrep", "--config", str(rules), "--json", "--metrics=off", str(fixture)],
+        capture_output=True,
+        text=True,
+        timeout=300,
+    )
+    if result.returncode not in (0, 1):
+        raise RuntimeError(f"semgrep failed on {fixture}: {result.stderr.strip()}")
+
+    # Local configs prefix rule ids with the config path; rule ids have no dots
+    return {
+        (finding["check_id"].rsplit(".", 1)[-1], finding["start"]["line"])
+        for finding in json.loads(result.stdout).get("results", [])
+    }
+
+
+def self_scan(
+    root: Union[str, Path] = RUST_FIXTURE_ROOT, rules: Path = RUST_RULES
+) -> set[str]:
+    """
+    Run the rule file over a whole tree and key each finding by function.
+
+    Entries read `<rule-id> <path>::<fn>`, with the path posix and relative to
+    root, so snapshots survive line shifts; findings outside any function
+    use `<module>` in place of the name.
+    """
+    root = Path(root).resolve()
+    result = subprocess.run(
+        ["semgrep", "--config", str(rules), "--json", "--metrics=off", str(root)],
+        capture_output=True,
+        text=True,
+        timeout=600,
+    )
+    if result.returncode not in (0, 1):
+        raise RuntimeError(f"semgrep failed on {root}: {result.stderr.strip()}")
+
+    sources: dict[Path, str] = {}
+    entries = set()
+    for finding in json.loads(result.stdout).get("results", []):
+        path = Path(finding["path"]).resolve()
+        if path not in sources:
+            sources[path] = path.read_text(encoding="utf-8")
+        function = enclosing_rust_function(sources[path], finding["start"]["line"])
+        rule = finding["check_id"].rsplit(".", 1)[-1]
+        relative = path.relative_to(root).as_posix()
+        entries.add(f"{rule} {relative}::{function or '<module>'}")
+    return entries
+
+
+def annotated_entries(root: Union[str, Path] = RUST_FIXTURE_ROOT) -> set[str]:
+    """Key every `ruleid` annotation under root the way self_scan keys findings."""
+    root = Path(root).resolve()
+    entries = set()
+    for fixture in annotated_fixtures(root):
+        source = fixture.read_text(encoding="utf-8")
+        expected, _clean = parse_annotations(source)
+        relative = fixture.resolve().relative_to(root).as_posix()
+        for rule, line in expected:
+            function = enclosing_rust_function(source, line)
+            entries.add(f"{rule} {relative}::{function or '<module>'}")
+    return entries
+
+
+def compare(
+    fixture: Path,
+    expected: set[Expectation],
+    clean: set[Expectation],
+    actual: set[Expectation],
+) -> list[str]:
+    """Describe every mismatch between annotations and findings."""
+    checked_rules = {rule for rule, _ in expected | clean}
+    relevant = {(rule, line) for rule, line in actual if rule in checked_rules}
+
+    problems = [
+        f"{fixture}:{line}: expected {rule} but it did not fire"
+        for rule, line in sorted(expected - relevant, key=lambda item: item[1])
+    ]
+    problems.extend(
+        f"{fixture}:{line}: unexpected {rule} finding"
+        + (" (annotated ok)" if (rule, line) in clean else "")
+        for rule, line in sorted(relevant - expected, key=lambda item: item[1])
+    )
+    return problems
+
+
+def check_fixture(fixture: Path, rules: Path = RUST_RULES) -> list[str]:
+    """Run the rules over an annotated fixture and return its mismatches."""
+    expected, clean = parse_annotations(fixture.read_text())
+    return compare(fixture, expected, clean, run_rules(fixture, rules))
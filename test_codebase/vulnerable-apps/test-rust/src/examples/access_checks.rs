@@ -0,0 +1,52 @@
+use std::process::Command;
+
+fn grant_access(user: &str) {
+    println!("access granted to {}", user);
+}
+
+fn record_backup(path: &str) {
+    println!("backup written to {}", path);
+}
+
+pub fn login_via_helper(user: &str, password: &str) -> std::io::Result<()> {
+    let output = Command::new("auth-helper").arg(user).arg(password).output()?;
+    if output.status.success() {
+        // ruleid: rust-command-output-trusted
+        grant_access(user);
+    }
+    Ok(())
+}
+
+pub fn admin_check(user: &str) -> std::io::Result<bool> {
+    let output = Command::new("id").arg("-Gn").arg(user).output()?;
+    if String::from_utf8_lossy(&output.stdout).contains("wheel") {
+        // ruleid: rust-command-output-trusted
+        return Ok(allow_admin(user));
+    }
+    Ok(false)
+}
+
+fn allow_admin(user: &str) -> bool {
+    !user.is_empty()
+}
+
+pub fn backup(path: &str) -> std::io::Result<()> {
+    let status = Command::new("tar").arg("czf").arg(path).arg("/srv/data").status()?;
+    if status.success() {
+        // ok: rust-command-output-trusted
+        record_backup(path);
+    }
+    Ok(())
+}
+
+fn password_matches(user: &str, password: &str) -> bool {
+    user.len() + password.len() > 12
+}
+
+pub fn login_in_process(user: &str, password: &str) {
+    // Granting access on an in-process check involves no command output
+    if password_matches(user, password) {
+        // ok: rust-command-output-trusted
+        grant_access(user);
+    }
+}
@@ -0,0 +1,47 @@
+use warp::Filter;
+
+const MAX_ROUNDS: u32 = 100;
+
+pub fn jobs_route() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("jobs" / u32).and_then(run_rounds)
+}
+
+async fn run_rounds(rounds: u32) -> Result<impl warp::Reply, warp::Rejection> {
+    let mut digest = 0u64;
+    // ruleid: rust-unbounded-loop-on-input
+    for round in 0..rounds {
+        digest = digest.wrapping_mul(31).wrapping_add(round as u64);
+    }
+    Ok(format!("digest {}", digest))
+}
+
+async fn drain_queue(pending: u32) -> Result<impl warp::Reply, warp::Rejection> {
+    let mut remaining = pending;
+    // ruleid: rust-unbounded-loop-on-input
+    while remaining > 0 {
+        remaining -= 1;
+    }
+    Ok("drained")
+}
+
+async fn run_rounds_capped(rounds: u32) -> Result<impl warp::Reply, warp::Rejection> {
+    let rounds = rounds.min(MAX_ROUNDS);
+    let mut digest = 0u64;
+    // ok: rust-unbounded-loop-on-input
+    for round in 0..rounds {
+        digest = digest.wrapping_add(round as u64);
+    }
+    Ok(format!("digest {}", digest))
+}
+
+async fn run_rounds_checked(rounds: u32) -> Result<impl warp::Reply, warp::Rejection> {
+    if rounds > MAX_ROUNDS {
+        return Err(warp::reject::not_found());
+    }
+    let mut digest = 0u64;
+    // ok: rust-unbounded-loop-on-input
+    for round in 0..rounds {
+        digest = digest.wrapping_add(round as u64);
+    }
+    Ok(format!("digest {}", digest))
+}
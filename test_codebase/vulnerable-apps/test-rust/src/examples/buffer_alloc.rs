@@ -0,0 +1,24 @@
+use warp::Filter;
+
+const MAX_BATCH: usize = 1024;
+
+pub fn batch_route() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("batch" / String).and_then(allocate_batch)
+}
+
+async fn allocate_batch(size: String) -> Result<impl warp::Reply, warp::Rejection> {
+    let count: usize = size.parse().map_err(|_| warp::reject::not_found())?;
+    // ruleid: rust-unbounded-allocation-from-input
+    let buffer: Vec<u64> = Vec::with_capacity(count);
+    Ok(format!("allocated {}", buffer.capacity()))
+}
+
+async fn allocate_batch_bounded(size: String) -> Result<impl warp::Reply, warp::Rejection> {
+    let count: usize = size.parse().map_err(|_| warp::reject::not_found())?;
+    let mut buffer: Vec<u64> = Vec::new();
+    // ok: rust-unbounded-allocation-from-input
+    buffer
+        .try_reserve_exact(count.min(MAX_BATCH))
+        .map_err(|_| warp::reject::not_found())?;
+    Ok(format!("allocated {}", buffer.capacity()))
+}
@@ -0,0 +1,27 @@
+// Synthetic build-time configuration; values come from the build environment
+
+// ruleid: rust-secret-baked-in-by-env-macro
+const API_KEY: &str = env!("API_KEY");
+
+// ruleid: rust-secret-baked-in-by-env-macro
+const AUTH_HEADER: &str = concat!("Bearer ", env!("DEPLOY_TOKEN"));
+
+// ok: rust-secret-baked-in-by-env-macro
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// ok: rust-secret-baked-in-by-env-macro
+const UPDATE_SIGNING_PUBLIC_KEY: &str = env!("UPDATE_SIGNING_PUBLIC_KEY");
+
+pub fn webhook_secret() -> String {
+    // ok: rust-secret-baked-in-by-env-macro
+    std::env::var("WEBHOOK_SECRET").expect("WEBHOOK_SECRET must be set")
+}
+
+pub fn client_headers() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("X-Api-Key", API_KEY),
+        ("Authorization", AUTH_HEADER),
+        ("X-Client-Version", VERSION),
+        ("X-Update-Key", UPDATE_SIGNING_PUBLIC_KEY),
+    ]
+}
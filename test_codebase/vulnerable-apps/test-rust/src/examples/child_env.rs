@@ -0,0 +1,46 @@
+use std::process::Command;
+
+const DEPLOY_TOKEN: &str = "dpl-token-0a1b2c3d4e5f6a7b8c9d";
+
+pub fn run_deploy_script() -> std::io::Result<std::process::ExitStatus> {
+    // ruleid: rust-secret-env-without-env-clear
+    Command::new("./deploy.sh")
+        .env("DEPLOY_TOKEN", DEPLOY_TOKEN)
+        .status()
+}
+
+pub fn run_deploy_script_scrubbed() -> std::io::Result<std::process::ExitStatus> {
+    // ok: rust-secret-env-without-env-clear
+    Command::new("./deploy.sh")
+        .env_clear()
+        .env("PATH", "/usr/bin:/bin")
+        .env("DEPLOY_TOKEN", DEPLOY_TOKEN)
+        .status()
+}
+
+pub fn run_deploy_script_scrubbed_midway() -> std::io::Result<std::process::ExitStatus> {
+    // ok: rust-secret-env-without-env-clear
+    Command::new("./deploy.sh")
+        .current_dir("/srv/app")
+        .env_clear()
+        .arg("--production")
+        .env("DEPLOY_TOKEN", DEPLOY_TOKEN)
+        .status()
+}
+
+pub fn run_deploy_script_scrubbed_after_args() -> std::io::Result<std::process::ExitStatus> {
+    // ok: rust-secret-env-without-env-clear
+    Command::new("./deploy.sh")
+        .arg("--production")
+        .env_clear()
+        .env("DEPLOY_TOKEN", DEPLOY_TOKEN)
+        .status()
+}
+
+pub fn run_deploy_script_scrubbed_separately() -> std::io::Result<std::process::ExitStatus> {
+    let mut cmd = Command::new("./deploy.sh");
+    cmd.env_clear();
+    // ok: rust-secret-env-without-env-clear
+    cmd.env("DEPLOY_TOKEN", DEPLOY_TOKEN);
+    cmd.status()
+}
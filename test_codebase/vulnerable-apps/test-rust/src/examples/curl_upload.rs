@@ -0,0 +1,31 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const API_KEY: &str = "sk-live-upload-0f1e2d3c4b5a69788796a5b4";
+
+pub fn upload_report(path: &str) -> std::io::Result<std::process::ExitStatus> {
+    // ruleid: rust-secret-in-command-args
+    Command::new("curl")
+        .arg("-H")
+        .arg(format!("Authorization: Bearer {}", API_KEY))
+        .arg("--data-binary")
+        .arg(format!("@{}", path))
+        .arg("https://reports.internal/upload")
+        .status()
+}
+
+pub fn upload_report_via_stdin(path: &str) -> std::io::Result<std::process::ExitStatus> {
+    // ok: rust-secret-in-command-args
+    let mut child = Command::new("curl")
+        .arg("-H")
+        .arg("@-")
+        .arg("--data-binary")
+        .arg(format!("@{}", path))
+        .arg("https://reports.internal/upload")
+        .stdin(Stdio::piped())
+        .spawn()?;
+    if let Some(stdin) = child.stdin.as_mut() {
+        writeln!(stdin, "Authorization: Bearer {}", API_KEY)?;
+    }
+    child.wait()
+}
@@ -0,0 +1,27 @@
+use std::fmt;
+
+// ruleid: rust-debug-derive-on-secret-fields
+#[derive(Debug, Clone)]
+pub struct Creds {
+    pub username: String,
+    pub password: String,
+}
+
+pub struct RedactedCreds {
+    pub username: String,
+    pub password: String,
+}
+
+impl fmt::Debug for RedactedCreds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedactedCreds")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+pub fn log_login_attempt(creds: &Creds) {
+    // ruleid: rust-debug-format-of-secret-value
+    println!("login attempt: {:?}", creds);
+}
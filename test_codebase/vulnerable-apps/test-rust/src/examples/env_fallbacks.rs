@@ -0,0 +1,24 @@
+use std::env;
+
+pub fn payment_api_key() -> &'static str {
+    option_env!("PAYMENT_API_KEY")
+        // ruleid: rust-secret-in-env-fallback
+        .unwrap_or("sk-live-4f3e2d1c0b9a8f7e6d5c4b3a")
+}
+
+pub fn session_secret() -> String {
+    env::var("SESSION_SECRET")
+        // ruleid: rust-secret-in-env-fallback
+        .unwrap_or_else(|_| "change-me-session-secret-123".to_string())
+}
+
+pub fn log_level() -> &'static str {
+    option_env!("LOG_LEVEL")
+        // ok: rust-secret-in-env-fallback
+        .unwrap_or("info")
+}
+
+pub fn required_api_key() -> String {
+    // ok: rust-secret-in-env-fallback
+    env::var("PAYMENT_API_KEY").expect("PAYMENT_API_KEY must be set")
+}
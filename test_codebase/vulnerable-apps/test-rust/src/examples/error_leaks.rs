@@ -0,0 +1,16 @@
+use std::collections::HashMap;
+
+pub fn connect_with_key(api_key: &str) -> HashMap<String, String> {
+    let connection: Result<HashMap<String, String>, String> = Err("timeout".to_string());
+    // ruleid: rust-sensitive-value-in-error-message
+    connection.expect(&format!("db error for {}", api_key))
+}
+
+pub fn run_lookup(user_id: &str) -> Result<String, String> {
+    let query = format!("SELECT * FROM accounts WHERE id = {}", user_id);
+    if user_id.is_empty() {
+        // ruleid: rust-sensitive-value-in-error-message
+        return Err(format!("lookup failed, query was {}", query));
+    }
+    Ok(query)
+}
@@ -0,0 +1,40 @@
+use std::fs;
+use std::path::{Component, Path};
+use warp::Filter;
+
+const UPLOAD_DIR: &str = "/var/app/uploads";
+
+pub fn download_route() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("files" / String).and_then(download)
+}
+
+async fn download(name: String) -> Result<impl warp::Reply, warp::Rejection> {
+    let base = Path::new(UPLOAD_DIR);
+    // ruleid: rust-path-traversal-join
+    let body = fs::read_to_string(base.join(name)).map_err(|_| warp::reject::not_found())?;
+    Ok(body)
+}
+
+async fn download_legacy(name: String) -> Result<impl warp::Reply, warp::Rejection> {
+    let path = format!("{}/{}", UPLOAD_DIR, name);
+    // ruleid: rust-path-traversal-join
+    let body = fs::read_to_string(&path).map_err(|_| warp::reject::not_found())?;
+    Ok(body)
+}
+
+async fn download_checked(name: String) -> Result<impl warp::Reply, warp::Rejection> {
+    if Path::new(&name).components().any(|c| !matches!(c, Component::Normal(_))) {
+        return Err(warp::reject::not_found());
+    }
+    let path = Path::new(UPLOAD_DIR).join(&name);
+    // ok: rust-path-traversal-join
+    let body = fs::read_to_string(path).map_err(|_| warp::reject::not_found())?;
+    Ok(body)
+}
+
+async fn download_readme(_name: String) -> Result<impl warp::Reply, warp::Rejection> {
+    // ok: rust-path-traversal-join
+    let body = fs::read_to_string(Path::new(UPLOAD_DIR).join("README.txt"))
+        .map_err(|_| warp::reject::not_found())?;
+    Ok(body)
+}
@@ -0,0 +1,24 @@
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+
+const SERVICE_TOKEN: &str = "svc-token-9f8e7d6c5b4a3210";
+
+pub fn write_world_readable_secret() -> std::io::Result<()> {
+    // ruleid: rust-secret-written-to-file
+    fs::write("/tmp/service_token", SERVICE_TOKEN)?;
+    // ruleid: rust-world-writable-permissions
+    fs::set_permissions("/tmp/service_token", fs::Permissions::from_mode(0o777))?;
+    Ok(())
+}
+
+pub fn relax_existing_permissions(path: &str) -> std::io::Result<()> {
+    let mut perms = fs::metadata(path)?.permissions();
+    // ruleid: rust-world-writable-permissions
+    perms.set_mode(0o666);
+    fs::set_permissions(path, perms)
+}
+
+pub fn restrict_permissions(path: &str) -> std::io::Result<()> {
+    // ok: rust-world-writable-permissions
+    fs::set_permissions(path, fs::Permissions::from_mode(0o600))
+}
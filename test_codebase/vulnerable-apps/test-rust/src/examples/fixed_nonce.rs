@@ -0,0 +1,20 @@
+// Synthetic AES-GCM fixture; the aes-gcm crate is not a dependency of this app
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+
+const FIXED_NONCE: [u8; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+
+pub fn encrypt_session(key: &Key<Aes256Gcm>, plaintext: &[u8]) -> Vec<u8> {
+    let cipher = Aes256Gcm::new(key);
+    let nonce = Nonce::from_slice(&FIXED_NONCE);
+    // ruleid: rust-fixed-nonce-or-iv
+    cipher.encrypt(nonce, plaintext).expect("encryption failure")
+}
+
+pub fn encrypt_session_safely(key: &Key<Aes256Gcm>, plaintext: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let cipher = Aes256Gcm::new(key);
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    // ok: rust-fixed-nonce-or-iv
+    let ciphertext = cipher.encrypt(&nonce, plaintext).expect("encryption failure");
+    (nonce.to_vec(), ciphertext)
+}
@@ -0,0 +1,21 @@
+use warp::Filter;
+
+pub fn greet_route() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path("greet")
+        .and(warp::path::param::<String>())
+        .and_then(greet)
+}
+
+// Synthetic: format! rejects non-literal format strings at compile time, but
+// macro wrappers and older panic! forms accept them, so scanners still flag it
+async fn greet(user_input: String) -> Result<impl warp::Reply, warp::Rejection> {
+    // ruleid: rust-format-string-injection
+    let greeting = format!(user_input);
+    Ok(warp::reply::html(greeting))
+}
+
+async fn greet_safely(user_input: String) -> Result<impl warp::Reply, warp::Rejection> {
+    // ok: rust-format-string-injection
+    let greeting = format!("{}", user_input);
+    Ok(warp::reply::html(greeting))
+}
@@ -0,0 +1,34 @@
+pub struct Signature(Vec<u8>);
+
+fn verify_signature(payload: &[u8], signature: &Signature) -> Result<(), String> {
+    if payload.len() == signature.0.len() {
+        Ok(())
+    } else {
+        Err("signature mismatch".to_string())
+    }
+}
+
+fn authenticate_user(username: &str, password: &str) -> Result<u64, String> {
+    if username == "admin" && !password.is_empty() {
+        Ok(1)
+    } else {
+        Err("invalid credentials".to_string())
+    }
+}
+
+pub fn process_webhook(payload: &[u8], signature: &Signature) -> String {
+    // ruleid: rust-ignored-security-result
+    let _ = verify_signature(payload, signature);
+    String::from_utf8_lossy(payload).to_string()
+}
+
+pub fn login(username: &str, password: &str) -> bool {
+    // ruleid: rust-ignored-security-result
+    authenticate_user(username, password).ok();
+    // ruleid: rust-ignored-security-result
+    if let Ok(_user_id) = authenticate_user(username, password) {
+        println!("welcome back");
+    } else {
+    }
+    true
+}
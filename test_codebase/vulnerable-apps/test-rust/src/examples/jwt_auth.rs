@@ -0,0 +1,30 @@
+// Synthetic jsonwebtoken fixture; the crate is not a dependency of this app
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+const JWT_SECRET: &str = "jwt-secret-key-abcdef123456789";
+
+#[derive(Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub exp: usize,
+}
+
+pub fn issue_token(user: &str) -> jsonwebtoken::errors::Result<String> {
+    let claims = Claims { sub: user.to_string(), exp: 2_000_000_000 };
+    encode(
+        &Header::new(Algorithm::HS256),
+        &claims,
+        // ruleid: rust-jwt-hardcoded-secret
+        &EncodingKey::from_secret(JWT_SECRET.as_bytes()),
+    )
+}
+
+pub fn read_token_unverified(token: &str) -> jsonwebtoken::errors::Result<Claims> {
+    let mut validation = Validation::new(Algorithm::HS256);
+    // ruleid: rust-jwt-signature-validation-disabled
+    validation.insecure_disable_signature_validation();
+    // ok: rust-jwt-hardcoded-secret
+    let key = DecodingKey::from_secret(&[]);
+    decode::<Claims>(token, &key, &validation).map(|data| data.claims)
+}
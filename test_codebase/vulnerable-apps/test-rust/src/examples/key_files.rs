@@ -0,0 +1,27 @@
+use std::fs::{self, File};
+use std::io::Write;
+use std::os::unix::fs::PermissionsExt;
+
+const API_KEY: &str = "sk-live-key-file-0a9b8c7d6e5f";
+
+pub fn export_key() -> std::io::Result<()> {
+    // ruleid: rust-secret-written-to-file
+    fs::write("key.txt", API_KEY)?;
+    // ruleid: rust-world-writable-permissions
+    fs::set_permissions("key.txt", fs::Permissions::from_mode(0o777))?;
+    Ok(())
+}
+
+pub fn save_session(session_token: &str) -> std::io::Result<()> {
+    let mut file = File::create("session.dat")?;
+    // ruleid: rust-secret-written-to-file
+    file.write_all(session_token.as_bytes())?;
+    Ok(())
+}
+
+pub fn save_report(report: &str) -> std::io::Result<()> {
+    // ok: rust-secret-written-to-file
+    fs::write("report.txt", report)?;
+    // ok: rust-world-writable-permissions
+    fs::set_permissions("report.txt", fs::Permissions::from_mode(0o644))
+}
@@ -0,0 +1,30 @@
+// Synthetic lazily initialized statics; once_cell/lazy_static are not dependencies
+use lazy_static::lazy_static;
+use once_cell::sync::Lazy;
+use std::sync::LazyLock;
+
+// ruleid: rust-secret-in-lazy-static
+static KEY: Lazy<String> = Lazy::new(|| "sk-live-9a8b7c6d5e4f3a2b1c0d".into());
+
+static SIGNING_SECRET: LazyLock<String> = LazyLock::new(|| {
+    // ruleid: rust-secret-in-lazy-static
+    String::from("hmac-signing-secret-2024")
+});
+
+lazy_static! {
+    // ruleid: rust-secret-in-lazy-static
+    static ref DEPLOY_TOKEN: String = "deploy-token-0f1e2d3c4b5a".to_string();
+    // ok: rust-secret-in-lazy-static
+    static ref CONFIG_PATH: String = "/etc/service/config.toml".to_string();
+}
+
+// ok: rust-secret-in-lazy-static
+static API_BASE: Lazy<String> = Lazy::new(|| "https://api.example.com/v2".into());
+
+// ok: rust-secret-in-lazy-static
+static API_KEY: Lazy<String> =
+    Lazy::new(|| std::env::var("API_KEY").expect("API_KEY must be set"));
+
+pub fn signed_request(body: &str) -> String {
+    format!("{}:{}:{}:{}", *KEY, *SIGNING_SECRET, *DEPLOY_TOKEN, body)
+}
@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+use warp::Filter;
+
+const PLANS: [&str; 3] = ["free", "team", "enterprise"];
+
+pub fn plans_route() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path("plan")
+        .and(warp::query::<HashMap<String, String>>())
+        .and_then(plan_by_index)
+}
+
+async fn plan_by_index(query: HashMap<String, String>) -> Result<impl warp::Reply, warp::Rejection> {
+    let index: usize = query.get("i").and_then(|v| v.parse().ok()).unwrap_or(0);
+    // ruleid: rust-unchecked-index-from-input
+    Ok(PLANS[index].to_string())
+}
+
+async fn page_slice(page: usize) -> Result<impl warp::Reply, warp::Rejection> {
+    let rows: Vec<String> = (0..50).map(|n| format!("row {}", n)).collect();
+    // ruleid: rust-unchecked-index-from-input
+    Ok(rows[page * 10].clone())
+}
+
+async fn plan_by_index_checked(query: HashMap<String, String>) -> Result<impl warp::Reply, warp::Rejection> {
+    let index: usize = query.get("i").and_then(|v| v.parse().ok()).unwrap_or(0);
+    if index >= PLANS.len() {
+        return Err(warp::reject::not_found());
+    }
+    // ok: rust-unchecked-index-from-input
+    Ok(PLANS[index].to_string())
+}
+
+async fn plan_by_index_get(query: HashMap<String, String>) -> Result<impl warp::Reply, warp::Rejection> {
+    let index: usize = query.get("i").and_then(|v| v.parse().ok()).unwrap_or(0);
+    // ok: rust-unchecked-index-from-input
+    let plan = PLANS.get(index).ok_or_else(warp::reject::not_found)?;
+    Ok(plan.to_string())
+}
@@ -0,0 +1,26 @@
+// Synthetic provenance fixtures; nothing here is called from main
+
+pub unsafe fn bump_shared_counter(counter: &u64) {
+    // ruleid: rust-const-to-mut-pointer-cast
+    let ptr = counter as *const u64 as *mut u64;
+    *ptr += 1;
+}
+
+pub unsafe fn overwrite_first_byte(buffer: &[u8]) {
+    // ruleid: rust-const-to-mut-pointer-cast
+    let ptr = buffer.as_ptr() as *mut u8;
+    *ptr = 0;
+}
+
+pub unsafe fn read_second_field(header: *const [u32; 2]) -> u32 {
+    let addr = header as usize;
+    // ruleid: rust-pointer-integer-round-trip
+    let second = (addr + 4) as *const u32;
+    *second
+}
+
+pub unsafe fn read_second_field_with_provenance(header: *const [u32; 2]) -> u32 {
+    // ok: rust-pointer-integer-round-trip
+    let second = (header as *const u32).add(1);
+    *second
+}
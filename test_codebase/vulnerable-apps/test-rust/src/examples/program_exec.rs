@@ -0,0 +1,27 @@
+use std::process::Command;
+use warp::Filter;
+
+pub fn tool_route() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("tools" / String).and_then(run_tool)
+}
+
+async fn run_tool(tool: String) -> Result<impl warp::Reply, warp::Rejection> {
+    // ruleid: rust-command-program-injection
+    let output = Command::new(tool)
+        .output()
+        .map_err(|_| warp::reject::not_found())?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+async fn run_allowed_tool(tool: String) -> Result<impl warp::Reply, warp::Rejection> {
+    let program = match tool.as_str() {
+        "uptime" => "/usr/bin/uptime",
+        "df" => "/bin/df",
+        _ => return Err(warp::reject::not_found()),
+    };
+    // ok: rust-command-program-injection
+    let output = Command::new(program)
+        .output()
+        .map_err(|_| warp::reject::not_found())?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
@@ -0,0 +1,11 @@
+use warp::Filter;
+
+pub fn greeting_route() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("greet" / String).and_then(greet_user)
+}
+
+async fn greet_user(name: String) -> Result<impl warp::Reply, warp::Rejection> {
+    let body = format!("<html><body><h1>Hello, {}!</h1></body></html>", name);
+    // ruleid: rust-reflected-input-html
+    Ok(warp::reply::html(body))
+}
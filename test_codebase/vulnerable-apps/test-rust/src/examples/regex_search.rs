@@ -0,0 +1,17 @@
+use regex::Regex;
+use warp::Filter;
+
+const LOG_LINES: [&str; 3] = ["GET /api/user 200", "POST /api/execute 500", "GET /health 200"];
+
+pub fn search_route() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path("search")
+        .and(warp::path::param::<String>())
+        .and_then(search_logs)
+}
+
+async fn search_logs(pattern: String) -> Result<impl warp::Reply, warp::Rejection> {
+    // ruleid: rust-regex-from-user-input
+    let matcher = Regex::new(&pattern).map_err(|_| warp::reject::not_found())?;
+    let hits: Vec<&str> = LOG_LINES.iter().copied().filter(|line| matcher.is_match(line)).collect();
+    Ok(warp::reply::json(&hits))
+}
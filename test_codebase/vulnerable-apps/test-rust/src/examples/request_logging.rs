@@ -0,0 +1,26 @@
+use std::collections::HashMap;
+use warp::Filter;
+
+pub fn login_route() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path("login")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(login)
+}
+
+// Body is {"username": ..., "password": ...}
+async fn login(params: HashMap<String, String>) -> Result<impl warp::Reply, warp::Rejection> {
+    // ruleid: rust-request-body-logged
+    println!("login request: {:?}", params);
+    let username = params.get("username").cloned().unwrap_or_default();
+    Ok(warp::reply::json(&username))
+}
+
+async fn login_with_field_logging(
+    params: HashMap<String, String>,
+) -> Result<impl warp::Reply, warp::Rejection> {
+    let username = params.get("username").cloned().unwrap_or_default();
+    // ok: rust-request-body-logged
+    println!("login request for user {}", username);
+    Ok(warp::reply::json(&username))
+}
@@ -0,0 +1,32 @@
+// Synthetic HTTP client fixtures; reqwest/native-tls/openssl are not dependencies
+use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
+
+pub fn internal_api_client() -> reqwest::Result<reqwest::Client> {
+    // ruleid: rust-tls-verification-disabled
+    reqwest::Client::builder()
+        .danger_accept_invalid_certs(true)
+        .build()
+}
+
+pub fn legacy_api_client() -> native_tls::Result<native_tls::TlsConnector> {
+    let mut builder = native_tls::TlsConnector::builder();
+    // ruleid: rust-tls-verification-disabled
+    builder.danger_accept_invalid_hostnames(true);
+    builder.build()
+}
+
+pub fn openssl_connector() -> Result<SslConnector, openssl::error::ErrorStack> {
+    let mut builder = SslConnector::builder(SslMethod::tls())?;
+    // ruleid: rust-tls-verification-disabled
+    builder.set_verify(SslVerifyMode::NONE);
+    Ok(builder.build())
+}
+
+pub fn pinned_ca_client(ca_pem: &[u8]) -> reqwest::Result<reqwest::Client> {
+    let ca = reqwest::Certificate::from_pem(ca_pem)?;
+    // ok: rust-tls-verification-disabled
+    reqwest::Client::builder()
+        .add_root_certificate(ca)
+        .danger_accept_invalid_certs(false)
+        .build()
+}
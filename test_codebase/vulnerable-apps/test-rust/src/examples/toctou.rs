@@ -0,0 +1,33 @@
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::path::Path;
+
+pub fn read_config(path: &Path) -> io::Result<String> {
+    if !path.exists() {
+        return Ok(String::new());
+    }
+    // ruleid: rust-toctou-path-check
+    fs::read_to_string(path)
+}
+
+pub fn create_lock(path: &Path) -> io::Result<File> {
+    if path.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, "locked"));
+    }
+    // ruleid: rust-toctou-path-check
+    File::create(path)
+}
+
+pub fn read_config_directly(path: &Path) -> io::Result<String> {
+    // ok: rust-toctou-path-check
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn create_lock_exclusively(path: &Path) -> io::Result<File> {
+    // ok: rust-toctou-path-check
+    OpenOptions::new().write(true).create_new(true).open(path)
+}
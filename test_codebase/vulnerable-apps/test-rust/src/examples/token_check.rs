@@ -0,0 +1,26 @@
+use subtle::ConstantTimeEq;
+
+const API_KEY: &str = "sk-live-1234567890abcdefghijklmnopqrstuvwxyz";
+
+pub fn is_authorized(user_token: &str) -> bool {
+    // ruleid: rust-non-constant-time-secret-comparison
+    if user_token == API_KEY {
+        return true;
+    }
+    false
+}
+
+pub fn is_authorized_constant_time(user_token: &str) -> bool {
+    // ok: rust-non-constant-time-secret-comparison
+    user_token.as_bytes().ct_eq(API_KEY.as_bytes()).into()
+}
+
+pub fn has_no_tokens(token_count: usize) -> bool {
+    // ok: rust-non-constant-time-secret-comparison
+    token_count == 0
+}
+
+pub fn is_same_host(machine_id: &str, expected_id: &str) -> bool {
+    // ok: rust-non-constant-time-secret-comparison
+    machine_id == expected_id
+}
@@ -0,0 +1,29 @@
+// Synthetic Vec::set_len fixtures; nothing here is called from main
+use std::ptr;
+
+pub fn read_buffer() -> Vec<u8> {
+    let mut v: Vec<u8> = Vec::with_capacity(100);
+    // ruleid: rust-vec-set-len-uninitialized
+    unsafe { v.set_len(100); }
+    v
+}
+
+pub fn copy_buffer(src: &[u8]) -> Vec<u8> {
+    let mut v: Vec<u8> = Vec::with_capacity(src.len());
+    unsafe {
+        ptr::copy_nonoverlapping(src.as_ptr(), v.as_mut_ptr(), src.len());
+        // ok: rust-vec-set-len-uninitialized
+        v.set_len(src.len());
+    }
+    v
+}
+
+pub fn zeroed_buffer(len: usize) -> Vec<u8> {
+    let mut v: Vec<u8> = Vec::with_capacity(len);
+    for slot in v.spare_capacity_mut() {
+        slot.write(0);
+    }
+    // ok: rust-vec-set-len-uninitialized
+    unsafe { v.set_len(len); }
+    v
+}
@@ -0,0 +1,24 @@
+use warp::Filter;
+
+pub fn proxy_routes() -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    let fetch = warp::path!("fetch" / String).and_then(fetch_preview);
+    let go = warp::path!("go" / String).and_then(follow_link);
+    fetch.or(go)
+}
+
+async fn fetch_preview(url: String) -> Result<impl warp::Reply, warp::Rejection> {
+    // ruleid: rust-ssrf-or-open-redirect
+    let body = reqwest::get(url)
+        .await
+        .map_err(|_| warp::reject::not_found())?
+        .text()
+        .await
+        .map_err(|_| warp::reject::not_found())?;
+    Ok(warp::reply::html(body))
+}
+
+async fn follow_link(target: String) -> Result<impl warp::Reply, warp::Rejection> {
+    let uri: warp::http::Uri = target.parse().map_err(|_| warp::reject::not_found())?;
+    // ruleid: rust-ssrf-or-open-redirect
+    Ok(warp::redirect::found(uri))
+}
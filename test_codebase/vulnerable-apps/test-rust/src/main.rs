@@ -7,8 +7,11 @@ use std::ptr;
 mod examples;
 mod web;
 
+// ruleid: rust-hardcoded-secret
 const API_KEY: &str = "sk-live-1234567890abcdefghijklmnopqrstuvwxyz";
+// ruleid: rust-hardcoded-secret
 const JWT_SECRET: &str = "jwt-secret-key-abcdef123456789";
+// ruleid: rust-hardcoded-secret
 const DATABASE_PASSWORD: &str = "rust-db-password-67890";
 
 fn main() {
@@ -23,6 +26,7 @@ fn main() {
 }
 
 pub fn command_injection_vuln(user_input: String) {
+    // ruleid: rust-command-injection
     let output = Command::new("sh")
         .arg("-c")
         .arg(&user_input)
@@ -33,6 +37,9 @@ pub fn command_injection_vuln(user_input: String) {
 }
 
 pub fn path_traversal_vuln(file_path: String) {
+    // Taint sources are warp handler parameters, so a plain fn argument is not
+    // treated as request input here
+    // ok: rust-path-traversal-join
     match fs::read_to_string(&file_path) {
         Ok(contents) => println!("File contents: {}", contents),
         Err(e) => println!("Error reading file: {}", e),
@@ -46,6 +53,7 @@ pub unsafe fn unsafe_buffer_overflow() {
     let buffer_ptr = buffer.as_mut_ptr();
     let source_ptr = source.as_ptr();
     
+    // ruleid: rust-buffer-overflow-unsafe
     ptr::copy_nonoverlapping(source_ptr, buffer_ptr, source.len());
     
     println!("Buffer overflow completed");
@@ -57,6 +65,7 @@ pub unsafe fn use_after_free_example() {
     
     std::alloc::dealloc(ptr, layout);
     
+    // ruleid: rust-use-after-free, rust-unchecked-alloc-null
     let value = ptr::read(ptr);
     println!("Use after free: {}", value);
 }
\ No newline at end of file
@@ -21,10 +21,12 @@ pub async fn start_server() {
 
 async fn execute_command(params: HashMap<String, String>) -> Result<impl warp::Reply, warp::Rejection> {
     if let Some(cmd) = params.get("command") {
+        // ruleid: rust-command-injection, rust-blocking-call-in-async-handler
         let output = Command::new("sh")
             .arg("-c")
             .arg(cmd)
             .output()
+            // ruleid: rust-error-discarded-in-handler
             .map_err(|_| warp::reject::not_found())?;
         
         Ok(warp::reply::json(&format!("Output: {}", String::from_utf8_lossy(&output.stdout))))
@@ -34,11 +36,13 @@ async fn execute_command(params: HashMap<String, String>) -> Result<impl warp::R
 }
 
 async fn get_user(user_id: String) -> Result<impl warp::Reply, warp::Rejection> {
+    // ruleid: rust-sql-injection
     let query = format!("SELECT * FROM users WHERE id = {}", user_id);
     
     let mut response = HashMap::new();
     response.insert("query", query);
     response.insert("user_id", user_id);
     
+    // ruleid: rust-reflected-input-json
     Ok(warp::reply::json(&response))
 }
\ No newline at end of file